@@ -0,0 +1,34 @@
+//! Compares repeated `checked_pow` calls against a shared `PowTable`
+//!
+//! Run with: cargo bench --bench pow_table
+
+use cosmwasm_custom_decimal::{Decimal18, PowTable};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Instant;
+
+fn main() {
+    // Per-block interest rate raised to many different block counts
+    let rate = Decimal18::from_str("1.000000001").unwrap();
+    let exponents: Vec<u32> = (0..200).map(|i| 1_000 + i * 37).collect();
+
+    let start = Instant::now();
+    for &exp in &exponents {
+        black_box(black_box(rate).checked_pow(exp));
+    }
+    let direct = start.elapsed();
+
+    let start = Instant::now();
+    let table = PowTable::new(rate);
+    for &exp in &exponents {
+        black_box(table.checked_pow(exp));
+    }
+    let tabled = start.elapsed();
+
+    println!("checked_pow x{}: {:?}", exponents.len(), direct);
+    println!("PowTable    x{}: {:?} (including table construction)", exponents.len(), tabled);
+    println!(
+        "speedup: {:.1}x",
+        direct.as_secs_f64() / tabled.as_secs_f64().max(f64::EPSILON)
+    );
+}
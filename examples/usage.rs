@@ -244,7 +244,7 @@ fn main() {
     // ========== Iterator Operations ==========
     println!("--- Iterator Operations ---");
 
-    let values = vec![
+    let values = [
         CustomDecimal::from_str("1.0").unwrap(),
         CustomDecimal::from_str("2.0").unwrap(),
         CustomDecimal::from_str("3.0").unwrap(),
@@ -299,8 +299,7 @@ fn main() {
     println!("Total (simple): {}", principal + simple_interest);
 
     // Compound interest: P * (1 + r)^t
-    let compound_multiplier = (CustomDecimal::ONE + annual_rate).pow(years);
-    let compound_total = principal * compound_multiplier;
+    let compound_total = principal.compound(annual_rate, years);
     let compound_interest = compound_total - principal;
     println!("Compound interest: {}", compound_interest);
     println!("Total (compound): {}", compound_total);
@@ -0,0 +1,44 @@
+use crate::Decimal;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Samples the raw `u128` atomics uniformly, so every `Decimal<D>` from `ZERO`
+/// to `MAX` can be produced. The same bytes always give the same value.
+impl<'a, const D: u32> Arbitrary<'a> for Decimal<D> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u128::arbitrary(u).map(Decimal::raw)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u128::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decimal6;
+
+    #[test]
+    fn test_arbitrary_is_deterministic() {
+        let bytes = [7u8; 16];
+        let a = Decimal6::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let b = Decimal6::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, Decimal6::raw(u128::from_le_bytes(bytes)));
+    }
+
+    #[test]
+    fn test_arbitrary_covers_the_bounds() {
+        let zero = Decimal6::arbitrary(&mut Unstructured::new(&[0u8; 16])).unwrap();
+        assert_eq!(zero, Decimal6::ZERO);
+        let max = Decimal6::arbitrary(&mut Unstructured::new(&[0xff; 16])).unwrap();
+        assert_eq!(max, Decimal6::MAX);
+
+        // Values parse back from their string form
+        let mut u = Unstructured::new(&[0x5a; 64]);
+        for _ in 0..4 {
+            let value = Decimal6::arbitrary(&mut u).unwrap();
+            assert_eq!(value.to_string().parse::<Decimal6>().unwrap(), value);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::Decimal;
+use borsh::io::{Error, ErrorKind, Read, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Borsh encoding of Decimal<D>: the precision `D` as a `u32` followed by the
+/// raw `u128` atomics, both little-endian (20 bytes).
+///
+/// Storing `D` lets decoding reject bytes written by a different precision,
+/// which would otherwise be silently misread by a factor of a power of ten.
+impl<const D: u32> BorshSerialize for Decimal<D> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        D.serialize(writer)?;
+        self.0.u128().serialize(writer)
+    }
+}
+
+/// Decoding fails with `ErrorKind::InvalidData` when the stored precision
+/// differs from `D`.
+impl<const D: u32> BorshDeserialize for Decimal<D> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let decimals = u32::deserialize_reader(reader)?;
+        if decimals != D {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Decimal precision mismatch in borsh data",
+            ));
+        }
+        u128::deserialize_reader(reader).map(Decimal::raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decimal18, Decimal6, Decimal9};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_borsh_roundtrip() {
+        for d9 in [
+            Decimal9::ZERO,
+            Decimal9::ONE,
+            Decimal9::MAX,
+            Decimal9::from_str("1.123456789").unwrap(),
+        ] {
+            let bytes = borsh::to_vec(&d9).unwrap();
+            assert_eq!(borsh::from_slice::<Decimal9>(&bytes).unwrap(), d9);
+        }
+    }
+
+    #[test]
+    fn test_borsh_layout() {
+        let bytes = borsh::to_vec(&Decimal6::raw(1_500_000)).unwrap();
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(bytes[..4], 6u32.to_le_bytes());
+        assert_eq!(bytes[4..], 1_500_000u128.to_le_bytes());
+    }
+
+    #[test]
+    fn test_borsh_precision_mismatch() {
+        let bytes = borsh::to_vec(&Decimal9::ONE).unwrap();
+
+        let err = borsh::from_slice::<Decimal6>(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(borsh::from_slice::<Decimal18>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_borsh_truncated_input() {
+        let bytes = borsh::to_vec(&Decimal9::ONE).unwrap();
+        assert!(borsh::from_slice::<Decimal9>(&bytes[..10]).is_err());
+    }
+}
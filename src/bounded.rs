@@ -0,0 +1,184 @@
+use crate::{pow10, CustomDecimalError, Decimal};
+use cosmwasm_schema::schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+
+/// A [`Decimal<D>`] guaranteed to lie within `[MIN_BPS / 10000, MAX_BPS / 10000]`.
+///
+/// The bounds are basis points, so `BoundedDecimal<6, 0, 10_000>` is a rate in
+/// `[0, 1]`. The range is checked once at construction (and on
+/// deserialization); afterwards the value derefs to the inner `Decimal<D>`.
+/// Below `D = 4` the bounds are truncated to `D` places like [`Decimal::bps`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::{BoundedDecimal, CustomDecimalError, Decimal6};
+///
+/// /// A fee rate between 0.1% and 5%
+/// type FeeRate = BoundedDecimal<6, 10, 500>;
+///
+/// let fee = FeeRate::new(Decimal6::percent(3)).unwrap();
+/// assert_eq!(*fee, Decimal6::percent(3));
+/// assert_eq!(FeeRate::new(Decimal6::percent(10)), Err(CustomDecimalError::RangeExceeded));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedDecimal<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32>(Decimal<D>);
+
+/// `bps / 10000` in `Decimal<D>` atomics. Scaling by `10^(D - 4)` avoids the
+/// `bps * 10^D` intermediate of `from_ratio_const`, which overflows for `D >= 35`.
+const fn bps_atomics<const D: u32>(bps: u32) -> u128 {
+    if D >= 4 {
+        bps as u128 * pow10(D - 4)
+    } else {
+        bps as u128 / pow10(4 - D)
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> BoundedDecimal<D, MIN_BPS, MAX_BPS> {
+    /// Evaluated by the bounds, so `MIN_BPS > MAX_BPS` fails to compile
+    const VALID_BOUNDS: () = assert!(
+        MIN_BPS <= MAX_BPS,
+        "BoundedDecimal requires MIN_BPS <= MAX_BPS"
+    );
+
+    /// The smallest allowed value, `MIN_BPS / 10000`
+    pub const MIN: Decimal<D> = {
+        let () = Self::VALID_BOUNDS;
+        Decimal::raw(bps_atomics::<D>(MIN_BPS))
+    };
+
+    /// The largest allowed value, `MAX_BPS / 10000`
+    pub const MAX: Decimal<D> = {
+        let () = Self::VALID_BOUNDS;
+        Decimal::raw(bps_atomics::<D>(MAX_BPS))
+    };
+
+    /// Wrap `value`, returning `RangeExceeded` if it lies outside `[MIN, MAX]`.
+    pub fn new(value: Decimal<D>) -> Result<Self, CustomDecimalError> {
+        if value < Self::MIN || value > Self::MAX {
+            return Err(CustomDecimalError::RangeExceeded);
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the inner value.
+    pub const fn get(self) -> Decimal<D> {
+        self.0
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> Deref for BoundedDecimal<D, MIN_BPS, MAX_BPS> {
+    type Target = Decimal<D>;
+
+    fn deref(&self) -> &Decimal<D> {
+        &self.0
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> TryFrom<Decimal<D>>
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    type Error = CustomDecimalError;
+
+    fn try_from(value: Decimal<D>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> From<BoundedDecimal<D, MIN_BPS, MAX_BPS>>
+    for Decimal<D>
+{
+    fn from(value: BoundedDecimal<D, MIN_BPS, MAX_BPS>) -> Self {
+        value.0
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> fmt::Display
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> fmt::Debug
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BoundedDecimal<{}, {}, {}>({})", D, MIN_BPS, MAX_BPS, self.0)
+    }
+}
+
+/// Same schema as the inner `Decimal<D>`; the bounds are not expressed.
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> JsonSchema
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    fn is_referenceable() -> bool {
+        Decimal::<D>::is_referenceable()
+    }
+
+    fn schema_name() -> String {
+        Decimal::<D>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        Decimal::<D>::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decimal6, Decimal9};
+
+    type FeeRate = BoundedDecimal<6, 10, 500>;
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(FeeRate::MIN, Decimal6::bps(10));
+        assert_eq!(FeeRate::MAX, Decimal6::percent(5));
+        assert_eq!(BoundedDecimal::<9, 0, 10_000>::MAX, Decimal9::ONE);
+        // Bounds beyond 100% are allowed, e.g. collateral ratios
+        assert_eq!(BoundedDecimal::<6, 11_000, 30_000>::MAX, Decimal6::from(3u64));
+
+        // High precision scales the bounds without a 10^D intermediate
+        assert_eq!(BoundedDecimal::<36, 0, 10_000>::MAX, Decimal::<36>::ONE);
+        assert_eq!(BoundedDecimal::<38, 10, 30_000>::MIN, Decimal::<38>::bps(10));
+        assert_eq!(BoundedDecimal::<38, 10, 30_000>::MAX, Decimal::<38>::from(3u64));
+        // Low precision truncates like Decimal::bps
+        assert_eq!(BoundedDecimal::<2, 50, 150>::MIN, Decimal::<2>::bps(50));
+        assert_eq!(BoundedDecimal::<2, 50, 150>::MAX, Decimal::<2>::raw(1));
+    }
+
+    #[test]
+    fn test_new_validates_range() {
+        let fee = FeeRate::new(Decimal6::percent(3)).unwrap();
+        assert_eq!(*fee, Decimal6::percent(3));
+        assert_eq!(fee.get(), Decimal6::percent(3));
+        assert_eq!(Decimal6::from(fee), Decimal6::percent(3));
+
+        // Both ends are inclusive
+        assert!(FeeRate::new(FeeRate::MIN).is_ok());
+        assert!(FeeRate::new(FeeRate::MAX).is_ok());
+
+        assert_eq!(FeeRate::new(Decimal6::percent(10)), Err(CustomDecimalError::RangeExceeded));
+        assert_eq!(
+            FeeRate::new(FeeRate::MIN - Decimal6::EPSILON),
+            Err(CustomDecimalError::RangeExceeded)
+        );
+        assert_eq!(
+            FeeRate::try_from(FeeRate::MAX + Decimal6::EPSILON),
+            Err(CustomDecimalError::RangeExceeded)
+        );
+    }
+
+    #[test]
+    fn test_deref_and_formatting() {
+        let fee = FeeRate::new(Decimal6::bps(25)).unwrap();
+        assert_eq!(fee.to_string(), "0.0025");
+        assert_eq!(format!("{:?}", fee), "BoundedDecimal<6, 10, 500>(0.0025)");
+        assert_eq!(fee.atomics(), 2_500);
+        assert_eq!(fee.checked_mul(Decimal6::from(4u64)), Some(Decimal6::percent(1)));
+    }
+}
@@ -0,0 +1,589 @@
+use crate::format::{display_digits, excess_zeros, pad_digits};
+use crate::{pow10, strip_separators, CustomDecimalError, Decimal, MAX_DECIMAL_PLACES};
+use alloc::format;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use cosmwasm_schema::schemars::gen::SchemaGenerator;
+use cosmwasm_schema::schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std::{Isqrt, Uint128, Uint256, Uint512};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// A fixed-point decimal with `D` decimal places backed by `Uint256` atomics.
+///
+/// This mirrors [`Decimal`] for values beyond `Uint128`: a `Decimal256Custom<18>`
+/// reaches about 1.15e59 instead of 3.4e20. Multiplication and division use
+/// `Uint512` intermediates. Like `Decimal`, `D` is limited to
+/// [`MAX_DECIMAL_PLACES`].
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::{Decimal18, Decimal256Custom};
+/// use std::str::FromStr;
+///
+/// let big = Decimal256Custom::<18>::from_str("1000000000000000000000000.5").unwrap();
+/// let doubled = big * Decimal256Custom::<18>::from_str("2").unwrap();
+/// assert_eq!(doubled.to_string(), "2000000000000000000000001");
+///
+/// // Values that fit convert back to the Uint128-backed type
+/// let small = Decimal256Custom::<18>::from(Decimal18::ONE);
+/// assert_eq!(Decimal18::try_from(small).unwrap(), Decimal18::ONE);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimal256Custom<const D: u32>(pub(crate) Uint256);
+
+impl<const D: u32> Decimal256Custom<D> {
+    // ========== Constants ==========
+
+    /// Evaluated by every constant and by `raw`, so `D > 38` fails to compile
+    const VALID_PRECISION: () = assert!(
+        D <= MAX_DECIMAL_PLACES,
+        "Decimal256Custom<D> supports at most 38 decimal places"
+    );
+
+    /// The fractional multiplier: 10^D
+    pub const FRACTIONAL: u128 = {
+        let () = Self::VALID_PRECISION;
+        pow10(D)
+    };
+
+    /// Zero decimal value
+    pub const ZERO: Self = Self::raw(0);
+
+    /// One decimal value (1.0)
+    pub const ONE: Self = Self::raw(Self::FRACTIONAL);
+
+    /// Maximum decimal value
+    pub const MAX: Self = Self(Uint256::MAX);
+
+    /// Number of decimal places
+    pub const DECIMAL_PLACES: u32 = D;
+
+    // ========== Construction ==========
+
+    /// Create from `Uint256` atomic units.
+    pub const fn new(atomics: Uint256) -> Self {
+        let () = Self::VALID_PRECISION;
+        Self(atomics)
+    }
+
+    /// Create from `u128` atomic units.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal256Custom;
+    /// let d = Decimal256Custom::<6>::raw(1_500_000); // 1.5
+    /// ```
+    pub const fn raw(atomics: u128) -> Self {
+        let () = Self::VALID_PRECISION;
+        Self(Uint256::from_u128(atomics))
+    }
+
+    /// Create from a ratio of two values, truncated toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero or the ratio exceeds `MAX`.
+    pub fn from_ratio(numerator: impl Into<Uint256>, denominator: impl Into<Uint256>) -> Self {
+        match Self::checked_from_ratio(numerator, denominator) {
+            Ok(value) => value,
+            Err(CustomDecimalError::DivisionByZero) => panic!("Denominator must not be zero"),
+            Err(_) => panic!("ratio overflow"),
+        }
+    }
+
+    /// Create from a ratio, returning `DivisionByZero` or `Overflow` instead of panicking.
+    pub fn checked_from_ratio(
+        numerator: impl Into<Uint256>,
+        denominator: impl Into<Uint256>,
+    ) -> Result<Self, CustomDecimalError> {
+        let numerator: Uint256 = numerator.into();
+        let denominator: Uint256 = denominator.into();
+        if denominator.is_zero() {
+            return Err(CustomDecimalError::DivisionByZero);
+        }
+
+        let result = numerator.full_mul(Uint256::from(Self::FRACTIONAL)) / Uint512::from(denominator);
+        Uint256::try_from(result)
+            .map(Self)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+
+    // ========== Accessors ==========
+
+    /// Returns the raw atomic value.
+    pub const fn atomics(&self) -> Uint256 {
+        self.0
+    }
+
+    /// Returns the number of decimal places.
+    pub const fn decimal_places(&self) -> u32 {
+        D
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    // ========== Checked Operations ==========
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).ok().map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).ok().map(Self)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let result = self.0.full_mul(other.0) / Uint512::from(Uint256::from(Self::FRACTIONAL));
+        Uint256::try_from(result).ok().map(Self)
+    }
+
+    /// Checked division. Returns `None` on division by zero or overflow.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0.is_zero() {
+            return None;
+        }
+        let result = self.0.full_mul(Uint256::from(Self::FRACTIONAL)) / Uint512::from(other.0);
+        Uint256::try_from(result).ok().map(Self)
+    }
+
+    /// Checked remainder. Returns `None` on division by zero.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        self.0.checked_rem(other.0).ok().map(Self)
+    }
+
+    /// Checked power by repeated squaring. Returns `None` on overflow.
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    // ========== Saturating Operations ==========
+
+    /// Saturating addition. Returns `MAX` on overflow.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction. Returns `ZERO` on underflow.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.checked_sub(other).unwrap_or(Self::ZERO)
+    }
+
+    /// Saturating multiplication. Returns `MAX` on overflow.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other).unwrap_or(Self::MAX)
+    }
+
+    // ========== Rounding & Math ==========
+
+    /// Returns the largest integer less than or equal to this value.
+    pub fn floor(self) -> Self {
+        let fractional = Uint256::from(Self::FRACTIONAL);
+        Self(self.0 / fractional * fractional)
+    }
+
+    /// Returns the smallest integer greater than or equal to this value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if that integer exceeds `MAX`. Use [`Decimal256Custom::checked_ceil`].
+    pub fn ceil(self) -> Self {
+        self.checked_ceil().expect("attempt to add with overflow")
+    }
+
+    /// Returns the smallest integer greater than or equal to this value,
+    /// or `None` if that integer exceeds `MAX`.
+    pub fn checked_ceil(self) -> Option<Self> {
+        let floor = self.floor();
+        if self == floor {
+            Some(floor)
+        } else {
+            floor.checked_add(Self::ONE)
+        }
+    }
+
+    /// Square root, truncated to `D` places.
+    pub fn sqrt(self) -> Self {
+        // atomics * 10^D fits in Uint512 and its root fits in Uint256
+        let scaled = self.0.full_mul(Uint256::from(Self::FRACTIONAL));
+        Self(Uint256::try_from(scaled.isqrt()).expect("sqrt overflow"))
+    }
+
+    /// Power function.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow. Use [`Decimal256Custom::checked_pow`] to handle it.
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).expect("overflow in pow")
+    }
+
+    // ========== Conversions ==========
+
+    /// Convert to an integer, rounding down.
+    pub fn to_uint_floor(self) -> Uint256 {
+        self.0 / Uint256::from(Self::FRACTIONAL)
+    }
+
+    /// Convert to an integer, rounding up.
+    pub fn to_uint_ceil(self) -> Uint256 {
+        let fractional = Uint256::from(Self::FRACTIONAL);
+        let floor = self.0 / fractional;
+        if (self.0 % fractional).is_zero() {
+            floor
+        } else {
+            floor + Uint256::one()
+        }
+    }
+
+    /// Parse a decimal string. With `truncate`, digits beyond `D` are dropped
+    /// (used for deserializing 18-decimal strings); otherwise they are an error.
+    /// The `strict-deserialize` feature only lets `truncate` drop zeros.
+    pub(crate) fn parse(s: &str, truncate: bool) -> Result<Self, CustomDecimalError> {
+        let s = &*strip_separators(s)?;
+        let (integer, fractional) = match s.split_once('.') {
+            Some((integer, fractional)) => (integer, fractional),
+            None => (s, ""),
+        };
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        // Either side of the point may be empty (".5", "1."), but not both
+        if s.is_empty() || s == "." || !all_digits(integer) || !all_digits(fractional) {
+            return Err(CustomDecimalError::ParseError(format!(
+                "Invalid decimal format: {}",
+                s
+            )));
+        }
+
+        let fractional = if fractional.len() > D as usize {
+            if !truncate {
+                return Err(CustomDecimalError::ParseError(format!(
+                    "Too many decimal places: {} (max {})",
+                    fractional.len(),
+                    D
+                )));
+            }
+            #[cfg(feature = "strict-deserialize")]
+            if fractional[D as usize..].bytes().any(|b| b != b'0') {
+                return Err(CustomDecimalError::ParseError(format!(
+                    "Precision loss: {} has more than {} significant decimal places",
+                    s, D
+                )));
+            }
+            &fractional[..D as usize]
+        } else {
+            fractional
+        };
+
+        let integer = match integer {
+            "" => Uint256::zero(),
+            integer => Uint256::from_str(integer).map_err(|_| CustomDecimalError::Overflow)?,
+        };
+        // At most 38 fractional digits, so the scaled fraction fits in u128
+        let scaled_fraction = if fractional.is_empty() {
+            0
+        } else {
+            fractional.parse::<u128>().unwrap() * pow10(D - fractional.len() as u32)
+        };
+
+        integer
+            .checked_mul(Uint256::from(Self::FRACTIONAL))
+            .and_then(|i| i.checked_add(Uint256::from(scaled_fraction)))
+            .map(Self)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+}
+
+// ========== Type Conversions ==========
+
+/// Widen a Decimal<D> losslessly
+impl<const D: u32> From<Decimal<D>> for Decimal256Custom<D> {
+    fn from(value: Decimal<D>) -> Self {
+        Self(Uint256::from(value.0))
+    }
+}
+
+/// Narrow to Decimal<D>, returning `Overflow` if the atomics exceed `Uint128`
+impl<const D: u32> TryFrom<Decimal256Custom<D>> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: Decimal256Custom<D>) -> Result<Self, Self::Error> {
+        Uint128::try_from(value.0)
+            .map(Decimal)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+}
+
+/// From Uint128 (treats as integer). `Uint128::MAX * 10^38` fits in `Uint256`.
+impl<const D: u32> From<Uint128> for Decimal256Custom<D> {
+    fn from(value: Uint128) -> Self {
+        Self(value.full_mul(Uint128::new(Self::FRACTIONAL)))
+    }
+}
+
+/// From u64 (treats as integer)
+impl<const D: u32> From<u64> for Decimal256Custom<D> {
+    fn from(value: u64) -> Self {
+        Self::from(Uint128::from(value))
+    }
+}
+
+// ========== Display & FromStr ==========
+
+/// Trailing fractional zeros are trimmed. Width, fill and alignment flags
+/// behave as for integers.
+/// Formats like `Decimal<D>`: trailing zeros are trimmed unless a precision
+/// is given (`{:.2}`), which rounds half-up.
+impl<const D: u32> fmt::Display for Decimal256Custom<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fractional = Uint256::from(Self::FRACTIONAL);
+        let integer = self.0 / fractional;
+        // The remainder is below 10^D, so it fits in a Decimal<D> below one.
+        // Rounding it up to precision carries into the integer part.
+        let frac_part = Uint128::try_from(self.0 % fractional).unwrap().u128();
+        let frac_digits = display_digits(Decimal::<D>::raw(frac_part), f.precision());
+        let (carry, frac_digits) = match frac_digits.split_once('.') {
+            Some((carry, frac_digits)) => (carry, Some(frac_digits)),
+            None => (frac_digits.as_str(), None),
+        };
+        let integer = if carry == "0" { integer } else { integer + Uint256::one() };
+
+        let digits = match frac_digits {
+            Some(frac_digits) => format!("{}.{}", integer, frac_digits),
+            None => integer.to_string(),
+        };
+        pad_digits(f, true, &digits, digits.len(), excess_zeros(f.precision()))
+    }
+}
+
+impl<const D: u32> fmt::Debug for Decimal256Custom<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Decimal256Custom<{}>({})", D, self)
+    }
+}
+
+impl<const D: u32> FromStr for Decimal256Custom<D> {
+    type Err = CustomDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, false)
+    }
+}
+
+// ========== Hash ==========
+
+/// Hashes the atomics, consistent with the derived `Eq`.
+impl<const D: u32> Hash for Decimal256Custom<D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_be_bytes().hash(state);
+    }
+}
+
+// ========== Schema ==========
+
+/// A decimal string compatible with `cosmwasm_std::Decimal256`, named per
+/// precision like `Decimal<D>`.
+impl<const D: u32> JsonSchema for Decimal256Custom<D> {
+    fn schema_name() -> String {
+        format!("Decimal256Custom{}", D)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Owned(format!("cosmwasm_custom_decimal::Decimal256Custom<{}>", D))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("decimal".to_string()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(format!(
+                    "A fixed-point decimal with {} decimal places backed by Uint256, serialized \
+                     as a string compatible with cosmwasm_std::Decimal256 (e.g. \"1.5\")",
+                    D
+                )),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^[0-9]+(\\.[0-9]{1,18})?$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decimal6;
+
+    type D6 = Decimal256Custom<6>;
+    type D18 = Decimal256Custom<18>;
+
+    fn d6(s: &str) -> D6 {
+        D6::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(D6::ZERO.atomics(), Uint256::zero());
+        assert_eq!(D6::ONE.atomics(), Uint256::from(1_000_000u128));
+        assert_eq!(D6::MAX.atomics(), Uint256::MAX);
+        assert_eq!(D18::DECIMAL_PLACES, 18);
+        assert_eq!(D18::ONE.decimal_places(), 18);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(d6("1.5") + d6("2.25"), d6("3.75"));
+        assert_eq!(d6("3.75") - d6("2.25"), d6("1.5"));
+        assert_eq!(d6("1.5") * d6("2.0"), d6("3"));
+        assert_eq!(d6("3.0") / d6("2.0"), d6("1.5"));
+        assert_eq!(d6("7.5") % d6("2.0"), d6("1.5"));
+        assert_eq!(d6("1") / d6("3"), d6("0.333333"));
+        assert_eq!(d6("2").pow(10), d6("1024"));
+        assert_eq!(d6("4").sqrt(), d6("2"));
+        assert_eq!(d6("2").sqrt(), d6("1.414213"));
+    }
+
+    #[test]
+    fn test_beyond_uint128() {
+        // 1e40 is far beyond Decimal6::MAX (~3.4e32)
+        let big = d6("100000000000000000000");
+        assert_eq!(big * big, d6("10000000000000000000000000000000000000000"));
+        assert!(Decimal6::try_from(big * big).is_err());
+
+        let ratio = D18::from_ratio(Uint256::from(u128::MAX), 3u128);
+        assert_eq!(ratio.to_uint_floor(), Uint256::from(u128::MAX / 3));
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        assert_eq!(D6::MAX.checked_add(D6::raw(1)), None);
+        assert_eq!(D6::ZERO.checked_sub(D6::raw(1)), None);
+        assert_eq!(D6::MAX.checked_mul(d6("2")), None);
+        assert_eq!(d6("1").checked_div(D6::ZERO), None);
+        assert_eq!(d6("1").checked_rem(D6::ZERO), None);
+        assert_eq!(d6("10").checked_pow(100), None);
+        assert_eq!(D6::MAX.saturating_add(D6::ONE), D6::MAX);
+        assert_eq!(D6::ZERO.saturating_sub(D6::ONE), D6::ZERO);
+        assert_eq!(D6::MAX.checked_ceil(), None);
+        assert_eq!(
+            D6::checked_from_ratio(1u128, 0u128),
+            Err(CustomDecimalError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(d6("1.7").floor(), d6("1"));
+        assert_eq!(d6("1.2").ceil(), d6("2"));
+        assert_eq!(d6("2").ceil(), d6("2"));
+        assert_eq!(d6("1.2").to_uint_ceil(), Uint256::from(2u8));
+        assert_eq!(d6("1.7").to_uint_floor(), Uint256::one());
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        assert_eq!(d6("1.500").to_string(), "1.5");
+        assert_eq!(d6("42").to_string(), "42");
+        assert_eq!(D6::raw(1).to_string(), "0.000001");
+        assert_eq!(format!("{:?}", d6("1.5")), "Decimal256Custom<6>(1.5)");
+        assert_eq!(format!("{:>6}", d6("1.5")), "   1.5");
+        assert_eq!(d6("1_000.5"), d6("1000.5"));
+
+        // Precision pads or rounds half-up, carrying into the integer part
+        assert_eq!(format!("{:.2}", d6("1.5")), "1.50");
+        assert_eq!(format!("{:.2}", d6("1.005")), "1.01");
+        assert_eq!(format!("{:.0}", d6("1.5")), "2");
+        assert_eq!(format!("{:.1}", d6("9.96")), "10.0");
+        assert_eq!(format!("{:>8.3}", d6("1.5")), "   1.500");
+        assert_eq!(format!("{:.40}", d6("1.5")), format!("1.5{}", "0".repeat(39)));
+        let big = "1000000000000000000000000000000000000000000000000000.5";
+        assert_eq!(
+            format!("{:.0}", D6::from_str(big).unwrap()),
+            "1000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(format!("{:.8}", d6("1.5")), format!("{:.8}", Decimal6::from_str("1.5").unwrap()));
+
+        let max = D6::MAX.to_string();
+        assert_eq!(D6::from_str(&max).unwrap(), D6::MAX);
+
+        assert!(D6::from_str("1.0000001").is_err());
+        assert!(D6::from_str("").is_err());
+        assert!(D6::from_str(".").is_err());
+        assert_eq!(D6::from_str("1.").unwrap(), D6::ONE);
+        assert_eq!(D6::from_str(".5").unwrap(), D6::raw(500_000));
+        assert!(D6::from_str("-1").is_err());
+        assert!(D6::from_str("1.2.3").is_err());
+        assert_eq!(
+            D6::from_str("1000000000000000000000000000000000000000000000000000000000000000000000000"),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_decimal_bridges() {
+        let d = Decimal6::from_str("123.456").unwrap();
+        let wide = D6::from(d);
+        assert_eq!(wide, d6("123.456"));
+        assert_eq!(Decimal6::try_from(wide), Ok(d));
+        assert_eq!(Decimal6::try_from(D6::from(Decimal6::MAX)), Ok(Decimal6::MAX));
+        assert_eq!(
+            Decimal6::try_from(D6::from(Decimal6::MAX) + D6::raw(1)),
+            Err(CustomDecimalError::Overflow)
+        );
+
+        assert_eq!(D6::from(5u64), d6("5"));
+        assert_eq!(
+            Decimal256Custom::<38>::from(Uint128::MAX).to_uint_floor(),
+            Uint256::from(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_hash_and_ordering() {
+        use std::collections::HashSet;
+        let set: HashSet<D6> = [d6("1.5"), d6("1.50"), d6("2")].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(d6("1.5") < d6("2"));
+        assert!(D6::MAX > D6::from(Decimal6::MAX));
+    }
+
+    #[test]
+    fn test_json_schema_describes_decimal() {
+        use cosmwasm_schema::schema_for;
+        let schema = serde_json::to_value(schema_for!(D6)).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["format"], "decimal");
+        assert_eq!(schema["pattern"], "^[0-9]+(\\.[0-9]{1,18})?$");
+        let description = schema["description"].as_str().unwrap();
+        assert!(description.contains("6 decimal places"));
+        assert!(description.contains("cosmwasm_std::Decimal256"));
+
+        assert_eq!(D6::schema_name(), "Decimal256Custom6");
+        assert_ne!(D6::schema_id(), D18::schema_id());
+        assert_ne!(D6::schema_id(), Decimal6::schema_id());
+    }
+}
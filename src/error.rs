@@ -1,8 +1,13 @@
+use alloc::format;
+use alloc::string::String;
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
 /// Errors that can occur when working with Decimal<D>
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm.
 #[derive(Error, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CustomDecimalError {
     /// Overflow during arithmetic operation
     #[error("Overflow in Decimal operation")]
@@ -28,12 +33,24 @@ pub enum CustomDecimalError {
     #[error("Conversion error: {0}")]
     ConversionError(String),
 
+    /// A nonzero value was lost to rounding at the target precision
+    #[error("Precision loss in Decimal operation")]
+    PrecisionLoss,
+
     /// Precision conversion overflow
     #[error("Precision conversion overflow: cannot convert from {from_decimals} to {to_decimals} decimals")]
     PrecisionConversionOverflow {
         from_decimals: u32,
         to_decimals: u32,
     },
+
+    /// Precision conversion overflow for the element at `index` of a slice
+    #[error("Precision conversion overflow at index {index}: cannot convert from {from_decimals} to {to_decimals} decimals")]
+    SliceConversionOverflow {
+        index: usize,
+        from_decimals: u32,
+        to_decimals: u32,
+    },
 }
 
 /// Convert CustomDecimalError to CosmWasm's StdError
@@ -52,12 +69,21 @@ impl From<CustomDecimalError> for StdError {
             CustomDecimalError::ConversionError(msg) => {
                 StdError::generic_err(format!("Conversion error: {}", msg))
             }
+            CustomDecimalError::PrecisionLoss => StdError::generic_err("Decimal precision loss"),
             CustomDecimalError::PrecisionConversionOverflow { from_decimals, to_decimals } => {
                 StdError::generic_err(format!(
                     "Precision conversion overflow: cannot convert from {} to {} decimals",
                     from_decimals, to_decimals
                 ))
             }
+            CustomDecimalError::SliceConversionOverflow {
+                index,
+                from_decimals,
+                to_decimals,
+            } => StdError::generic_err(format!(
+                "Precision conversion overflow at index {}: cannot convert from {} to {} decimals",
+                index, from_decimals, to_decimals
+            )),
         }
     }
 }
@@ -95,6 +121,10 @@ mod tests {
             CustomDecimalError::ParseError("test".to_string()).to_string(),
             "Failed to parse Decimal: test"
         );
+        assert_eq!(
+            CustomDecimalError::PrecisionLoss.to_string(),
+            "Precision loss in Decimal operation"
+        );
     }
 
     #[test]
@@ -110,5 +140,15 @@ mod tests {
 
         let std_err: StdError = err.into();
         assert!(std_err.to_string().contains("6 to 18"));
+
+        let err = CustomDecimalError::SliceConversionOverflow {
+            index: 2,
+            from_decimals: 6,
+            to_decimals: 18,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Precision conversion overflow at index 2: cannot convert from 6 to 18 decimals"
+        );
     }
 }
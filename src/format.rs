@@ -0,0 +1,462 @@
+use crate::{pow10, Decimal, MAX_DECIMAL_PLACES};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+/// Where the currency symbol is placed relative to the number
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// Before the number, e.g. `$1.00`
+    #[default]
+    Prefix,
+    /// After the number, e.g. `1,00 €`
+    Suffix,
+}
+
+/// Options for [`Decimal::format_money`].
+///
+/// The symbol is inserted verbatim, so include any spacing in it (e.g. `" €"`).
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::{Decimal6, MoneyFormat, SymbolPosition};
+/// use std::str::FromStr;
+///
+/// let euro = MoneyFormat {
+///     group_separator: Some('.'),
+///     decimal_separator: ',',
+///     symbol: Some(" €".to_string()),
+///     symbol_position: SymbolPosition::Suffix,
+///     ..MoneyFormat::default()
+/// };
+/// let d = Decimal6::from_str("1234.5").unwrap();
+/// assert_eq!(d.format_money(&euro), "1.234,50 €");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoneyFormat {
+    /// Separator inserted every three integer digits, or `None` for no grouping
+    pub group_separator: Option<char>,
+    /// Separator between the integer and fractional digits
+    pub decimal_separator: char,
+    /// Number of fractional digits to print, at most [`MAX_DECIMAL_PLACES`].
+    /// Values are rounded half-up.
+    pub fractional_digits: u32,
+    /// Optional currency symbol
+    pub symbol: Option<String>,
+    /// Placement of the currency symbol
+    pub symbol_position: SymbolPosition,
+}
+
+impl Default for MoneyFormat {
+    /// `1,234.50` style: comma grouping, point decimal, two digits, no symbol
+    fn default() -> Self {
+        Self {
+            group_separator: Some(','),
+            decimal_separator: '.',
+            fractional_digits: 2,
+            symbol: None,
+            symbol_position: SymbolPosition::Prefix,
+        }
+    }
+}
+
+impl<const D: u32> Decimal<D> {
+    /// Format for invoices and receipts with grouping, fixed fractional digits and
+    /// an optional currency symbol.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, MoneyFormat};
+    /// use std::str::FromStr;
+    ///
+    /// let usd = MoneyFormat { symbol: Some("$".to_string()), ..MoneyFormat::default() };
+    /// let d = Decimal6::from_str("1234.5").unwrap();
+    /// assert_eq!(d.format_money(&usd), "$1,234.50");
+    /// ```
+    pub fn format_money(&self, opts: &MoneyFormat) -> String {
+        // The digits are allocated, so cap caller-supplied counts
+        let places = opts.fractional_digits.min(MAX_DECIMAL_PLACES);
+        let (integer, fractional) = rounded_parts(*self, places);
+
+        let mut number = match opts.group_separator {
+            Some(separator) => group_digits(&integer.to_string(), separator),
+            None => integer.to_string(),
+        };
+        if !fractional.is_empty() {
+            number.push(opts.decimal_separator);
+            number.push_str(&fractional);
+        }
+
+        match (&opts.symbol, opts.symbol_position) {
+            (Some(symbol), SymbolPosition::Prefix) => format!("{}{}", symbol, number),
+            (Some(symbol), SymbolPosition::Suffix) => format!("{}{}", number, symbol),
+            (None, _) => number,
+        }
+    }
+
+    /// Format with exactly `places` fractional digits, padding with zeros or
+    /// rounding half-up when `places < D`. `places` is capped at
+    /// [`MAX_DECIMAL_PLACES`].
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    ///
+    /// let d = Decimal6::from_str("1.5").unwrap();
+    /// assert_eq!(d.to_string_with_precision(2), "1.50");
+    /// assert_eq!(d.to_string_with_precision(0), "2");
+    /// ```
+    pub fn to_string_with_precision(&self, places: u32) -> String {
+        display_digits(*self, Some(places as usize))
+    }
+
+    /// Format like `Display` (trailing zeros trimmed), inserting `group` every
+    /// three integer digits and using `decimal_point` before the fraction.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    ///
+    /// let d = Decimal6::from_str("1000000.5").unwrap();
+    /// assert_eq!(d.format_with_separators('.', ','), "1.000.000,5");
+    /// ```
+    pub fn format_with_separators(&self, group: char, decimal_point: char) -> String {
+        let digits = display_digits(*self, None);
+        let (integer, fractional) = match digits.split_once('.') {
+            Some((integer, fractional)) => (integer, Some(fractional)),
+            None => (digits.as_str(), None),
+        };
+
+        let mut grouped = group_digits(integer, group);
+        if let Some(fractional) = fractional {
+            grouped.push(decimal_point);
+            grouped.push_str(fractional);
+        }
+        grouped
+    }
+
+    /// [`Decimal::format_with_separators`] with `,` grouping and a `.` decimal
+    /// point, e.g. `1,000,000.5`.
+    pub fn format_grouped(&self) -> String {
+        self.format_with_separators(',', '.')
+    }
+}
+
+/// Split into the integer part and exactly `places` fractional digits, rounding
+/// half-up when `places < D`.
+pub(crate) fn rounded_parts<const D: u32>(value: Decimal<D>, places: u32) -> (u128, String) {
+    let atomics = value.0.u128();
+
+    if places >= D {
+        let integer = atomics / Decimal::<D>::FRACTIONAL;
+        let fraction = atomics % Decimal::<D>::FRACTIONAL;
+        let mut digits = if D == 0 {
+            String::new()
+        } else {
+            format!("{:0>width$}", fraction, width = D as usize)
+        };
+        digits.push_str(&"0".repeat((places - D) as usize));
+        return (integer, digits);
+    }
+
+    // Round half-up to `places` digits. The divisor is an even power of ten,
+    // and at least 10, so adding one to the quotient cannot overflow.
+    let divisor = pow10(D - places);
+    let mut rounded = atomics / divisor;
+    if atomics % divisor >= divisor / 2 {
+        rounded += 1;
+    }
+
+    let unit = pow10(places);
+    let integer = rounded / unit;
+    let digits = if places == 0 {
+        String::new()
+    } else {
+        format!("{:0>width$}", rounded % unit, width = places as usize)
+    };
+    (integer, digits)
+}
+
+/// Unsigned digits for `Display`: exactly `precision` fractional digits
+/// (rounded half-up) when given, otherwise all digits with trailing zeros
+/// trimmed.
+///
+/// At most `MAX_DECIMAL_PLACES` digits are allocated; formatters write the
+/// zeros of a longer precision with [`pad_digits`].
+pub(crate) fn display_digits<const D: u32>(value: Decimal<D>, precision: Option<usize>) -> String {
+    let (integer, fractional) = match precision {
+        Some(places) => rounded_parts(value, places.min(MAX_DECIMAL_PLACES as usize) as u32),
+        None => {
+            let (integer, mut digits) = rounded_parts(value, D);
+            digits.truncate(digits.trim_end_matches('0').len());
+            (integer, digits)
+        }
+    };
+
+    if fractional.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fractional)
+    }
+}
+
+/// Unsigned digits for `LowerExp`/`UpperExp`: a mantissa in `[1, 10)` and an
+/// exact exponent derived from the atomics. With a precision the mantissa has
+/// exactly that many fractional digits, rounded half-up; otherwise trailing
+/// zeros are trimmed.
+///
+/// Like [`display_digits`], at most `MAX_DECIMAL_PLACES` mantissa digits are
+/// allocated; formatters insert the rest before the exponent marker.
+pub(crate) fn exp_digits<const D: u32>(
+    value: Decimal<D>,
+    precision: Option<usize>,
+    upper: bool,
+) -> String {
+    // u128 has at most 39 digits, so more places would only add zeros
+    let precision = precision.map(|places| places.min(MAX_DECIMAL_PLACES as usize));
+    let atomics = value.0.u128();
+    let marker = if upper { 'E' } else { 'e' };
+    let mut digits = atomics.to_string();
+    // Zero has no leading digit to normalise; its exponent is 0
+    let mut exponent = if atomics == 0 {
+        0
+    } else {
+        digits.len() as i64 - 1 - i64::from(D)
+    };
+
+    match precision {
+        Some(places) if digits.len() > places + 1 => {
+            // At most 38 digits are kept, so the rounded prefix fits in u128
+            let mut kept: u128 = digits[..=places].parse().unwrap();
+            if digits.as_bytes()[places + 1] >= b'5' {
+                kept += 1;
+            }
+            digits = kept.to_string();
+            if digits.len() > places + 1 {
+                // Rounding carried into a new leading digit, e.g. 9.99 -> 10.0
+                digits.truncate(places + 1);
+                exponent += 1;
+            }
+        }
+        Some(places) => {
+            digits.push_str(&"0".repeat(places + 1 - digits.len()));
+        }
+        None => {
+            let significant = digits.trim_end_matches('0').len().max(1);
+            digits.truncate(significant);
+        }
+    }
+
+    let (lead, rest) = digits.split_at(1);
+    if rest.is_empty() {
+        format!("{}{}{}", lead, marker, exponent)
+    } else {
+        format!("{}.{}{}{}", lead, rest, marker, exponent)
+    }
+}
+
+/// Zeros a `{:.N}` precision asks for beyond the `MAX_DECIMAL_PLACES` digits
+/// that [`display_digits`] and [`exp_digits`] allocate. Every digit past that
+/// is zero, since the atomics have at most 39 significant digits.
+pub(crate) fn excess_zeros(precision: Option<usize>) -> usize {
+    precision.map_or(0, |places| places.saturating_sub(MAX_DECIMAL_PLACES as usize))
+}
+
+/// Write `digits` like `Formatter::pad_integral`, inserting `zeros` zeros at
+/// byte offset `at` straight into the formatter, so the precision is honoured
+/// without allocating the padding. Width, fill, alignment and the `+` and
+/// `0` flags count the inserted zeros.
+pub(crate) fn pad_digits(
+    f: &mut fmt::Formatter,
+    is_nonnegative: bool,
+    digits: &str,
+    at: usize,
+    zeros: usize,
+) -> fmt::Result {
+    if zeros == 0 {
+        return f.pad_integral(is_nonnegative, "", digits);
+    }
+
+    let sign = match (is_nonnegative, f.sign_plus()) {
+        (false, _) => "-",
+        (true, true) => "+",
+        (true, false) => "",
+    };
+    let len = sign.len() + digits.len() + zeros;
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after, fill) = if f.sign_aware_zero_pad() {
+        (0, 0, '0')
+    } else {
+        match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding, f.fill()),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2, f.fill()),
+            _ => (padding, 0, f.fill()),
+        }
+    };
+
+    fn write_repeated(f: &mut fmt::Formatter, c: char, count: usize) -> fmt::Result {
+        for _ in 0..count {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    write_repeated(f, fill, before)?;
+    f.write_str(sign)?;
+    if f.sign_aware_zero_pad() {
+        write_repeated(f, '0', padding)?;
+    }
+    f.write_str(&digits[..at])?;
+    write_repeated(f, '0', zeros)?;
+    f.write_str(&digits[at..])?;
+    write_repeated(f, fill, after)
+}
+
+/// Insert `separator` every three digits from the right
+pub(crate) fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decimal18, Decimal6};
+    use std::str::FromStr;
+
+    fn usd() -> MoneyFormat {
+        MoneyFormat {
+            symbol: Some("$".to_string()),
+            ..MoneyFormat::default()
+        }
+    }
+
+    fn euro() -> MoneyFormat {
+        MoneyFormat {
+            group_separator: Some('.'),
+            decimal_separator: ',',
+            symbol: Some(" €".to_string()),
+            symbol_position: SymbolPosition::Suffix,
+            ..MoneyFormat::default()
+        }
+    }
+
+    #[test]
+    fn test_format_money_usd() {
+        let d = Decimal6::from_str("1234.5").unwrap();
+        assert_eq!(d.format_money(&usd()), "$1,234.50");
+        assert_eq!(Decimal6::ZERO.format_money(&usd()), "$0.00");
+        assert_eq!(
+            Decimal6::from_str("1234567.891").unwrap().format_money(&usd()),
+            "$1,234,567.89"
+        );
+    }
+
+    #[test]
+    fn test_format_money_euro() {
+        let d = Decimal6::from_str("1234.5").unwrap();
+        assert_eq!(d.format_money(&euro()), "1.234,50 €");
+        assert_eq!(
+            Decimal6::from_str("999.999").unwrap().format_money(&euro()),
+            "1.000,00 €"
+        );
+    }
+
+    #[test]
+    fn test_format_money_no_symbol() {
+        let d = Decimal6::from_str("1234.5").unwrap();
+        assert_eq!(d.format_money(&MoneyFormat::default()), "1,234.50");
+
+        let plain = MoneyFormat {
+            group_separator: None,
+            fractional_digits: 0,
+            ..MoneyFormat::default()
+        };
+        assert_eq!(d.format_money(&plain), "1235");
+    }
+
+    #[test]
+    fn test_format_money_rounding() {
+        let opts = MoneyFormat::default();
+        assert_eq!(Decimal6::from_str("1.005").unwrap().format_money(&opts), "1.01");
+        assert_eq!(Decimal6::from_str("1.004999").unwrap().format_money(&opts), "1.00");
+
+        let precise = MoneyFormat {
+            fractional_digits: 8,
+            ..MoneyFormat::default()
+        };
+        assert_eq!(
+            Decimal6::from_str("1.5").unwrap().format_money(&precise),
+            "1.50000000"
+        );
+        assert_eq!(
+            Decimal18::from_str("0.125").unwrap().format_money(&opts),
+            "0.13"
+        );
+    }
+
+    #[test]
+    fn test_format_money_caps_fractional_digits() {
+        let huge = MoneyFormat {
+            fractional_digits: u32::MAX,
+            ..MoneyFormat::default()
+        };
+        let formatted = Decimal6::from_str("1.5").unwrap().format_money(&huge);
+        assert_eq!(formatted, format!("1.5{}", "0".repeat(37)));
+        assert_eq!(Decimal6::ONE.to_string_with_precision(u32::MAX).len(), 40);
+    }
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(group_digits("0", ','), "0");
+        assert_eq!(group_digits("123", ','), "123");
+        assert_eq!(group_digits("1234", ','), "1,234");
+        assert_eq!(group_digits("1000000", ','), "1,000,000");
+    }
+
+    #[test]
+    fn test_to_string_with_precision() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        assert_eq!(d.to_string_with_precision(2), "1.50");
+        assert_eq!(d.to_string_with_precision(4), "1.5000");
+        assert_eq!(d.to_string_with_precision(6), "1.500000");
+        assert_eq!(d.to_string_with_precision(8), "1.50000000");
+
+        // Fewer places than D round half-up
+        let d = Decimal6::from_str("2.344501").unwrap();
+        assert_eq!(d.to_string_with_precision(4), "2.3445");
+        assert_eq!(d.to_string_with_precision(3), "2.345");
+        assert_eq!(d.to_string_with_precision(1), "2.3");
+
+        // Integer-only output
+        assert_eq!(Decimal6::from_str("2.5").unwrap().to_string_with_precision(0), "3");
+        assert_eq!(Decimal6::from_str("2.4").unwrap().to_string_with_precision(0), "2");
+        assert_eq!(Decimal6::ZERO.to_string_with_precision(0), "0");
+        assert_eq!(Decimal18::ONE.to_string_with_precision(3), "1.000");
+    }
+
+    #[test]
+    fn test_format_with_separators() {
+        let d = Decimal6::from_str("1000000.5").unwrap();
+        assert_eq!(d.format_grouped(), "1,000,000.5");
+        assert_eq!(d.format_with_separators('.', ','), "1.000.000,5");
+        assert_eq!(d.format_with_separators(' ', ','), "1 000 000,5");
+
+        assert_eq!(Decimal6::ZERO.format_grouped(), "0");
+        assert_eq!(Decimal6::from_str("0.5").unwrap().format_grouped(), "0.5");
+        assert_eq!(Decimal6::from(1234u64).format_grouped(), "1,234");
+        assert_eq!(Decimal6::from(999u64).format_grouped(), "999");
+        assert_eq!(
+            Decimal6::from_str("1234567.000001").unwrap().format_grouped(),
+            "1,234,567.000001"
+        );
+    }
+}
@@ -32,31 +32,102 @@
 //! let converted: Decimal9 = d6.to_precision();
 //! ```
 
-use cosmwasm_schema::schemars::{self, JsonSchema};
-use cosmwasm_std::{Decimal as StdDecimal, Decimal256, Uint128, Uint256};
-use std::fmt;
-use std::iter::{Product, Sum};
-use std::str::FromStr;
-
+// Unit tests always link std for the test harness; `tests/no_std_smoke.rs`
+// checks the library itself with `--no-default-features`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use cosmwasm_schema::schemars::gen::SchemaGenerator;
+use cosmwasm_schema::schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std::{Decimal as StdDecimal, Decimal256, Fraction, Isqrt, Uint128, Uint256, Uint64};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::str::FromStr;
+
+use rounding::div_rounded;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+mod bounded;
+mod decimal256;
 mod error;
+mod format;
 mod ops;
+mod pow_table;
+#[cfg(feature = "primitive-types")]
+mod primitive_types_impl;
+#[cfg(feature = "proptest")]
+pub mod proptest_strategy;
+mod rounding;
+pub mod serde_atomics;
 mod serde_impl;
-
+pub mod serde_native;
+mod signed;
+#[cfg(feature = "storage-plus")]
+mod storage_plus_impl;
+mod transcendental;
+
+pub use bounded::BoundedDecimal;
+pub use decimal256::Decimal256Custom;
 pub use error::CustomDecimalError;
+pub use format::{MoneyFormat, SymbolPosition};
+pub use pow_table::PowTable;
+pub use rounding::RoundingMode;
+pub use serde_impl::DeserializeMode;
+pub use signed::SignedDecimal;
 
 // ========== Const Helper Functions ==========
 
+/// Largest supported number of decimal places: `10^38` is the highest power of ten in `u128`
+pub const MAX_DECIMAL_PLACES: u32 = 38;
+
 /// Compute 10^exp at compile time
+///
+/// # Panics
+///
+/// Panics if `exp > MAX_DECIMAL_PLACES`. In const contexts this is a compile error.
 pub const fn pow10(exp: u32) -> u128 {
     let mut result: u128 = 1;
     let mut i = 0;
     while i < exp {
-        result *= 10;
+        result = match result.checked_mul(10) {
+            Some(next) => next,
+            None => panic!("10^exp overflows u128 (exp must be at most 38)"),
+        };
         i += 1;
     }
     result
 }
 
+/// Returns `k` if `value == 10^k`, otherwise `None`
+const fn pow10_exponent(value: u128) -> Option<u32> {
+    if value == 0 {
+        return None;
+    }
+    let mut v = value;
+    let mut k = 0;
+    while v % 10 == 0 {
+        v /= 10;
+        k += 1;
+    }
+    if v == 1 {
+        Some(k)
+    } else {
+        None
+    }
+}
+
 /// Compute the scale factor to convert from D decimals to 18 decimals
 pub const fn scale_factor_to_18<const D: u32>() -> u128 {
     if D >= 18 {
@@ -75,6 +146,25 @@ pub const fn scale_factor_from_18<const D: u32>() -> u128 {
     }
 }
 
+/// Compute the factor converting `FROM` decimals to `TO` decimals, and whether
+/// it scales up (multiply) rather than down (divide).
+///
+/// Equal precisions give `(1, true)`.
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::scale_between;
+/// assert_eq!(scale_between::<6, 9>(), (1_000, true));
+/// assert_eq!(scale_between::<9, 6>(), (1_000, false));
+/// ```
+pub const fn scale_between<const FROM: u32, const TO: u32>() -> (u128, bool) {
+    if FROM <= TO {
+        (pow10(TO - FROM), true)
+    } else {
+        (pow10(FROM - TO), false)
+    }
+}
+
 // ========== Legacy Constants (for backward compatibility) ==========
 
 /// Number of decimal places for CustomDecimal (default: 6)
@@ -121,6 +211,13 @@ pub type Decimal18 = Decimal<18>;
 /// let _ = d6 + d9; // Compile error: mismatched types
 /// ```
 ///
+/// `D` is limited to [`MAX_DECIMAL_PLACES`] (38). Larger precisions are rejected at
+/// compile time instead of producing wrapped-around atomics:
+/// ```compile_fail
+/// use cosmwasm_custom_decimal::Decimal;
+/// let _ = Decimal::<39>::ONE; // Compile error: at most 38 decimal places
+/// ```
+///
 /// Use `to_precision()` to convert between precisions:
 /// ```
 /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
@@ -143,24 +240,35 @@ pub type Decimal18 = Decimal<18>;
 /// // 9-decimal precision
 /// let c = Decimal::<9>::from_str("1.123456789").unwrap();
 /// ```
-#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
-#[schemars(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Decimal<const D: u32>(pub(crate) Uint128);
 
 impl<const D: u32> Decimal<D> {
     // ========== Constants ==========
 
+    /// Evaluated by every constant and by `raw`, so `D > 38` fails to compile
+    const VALID_PRECISION: () = assert!(
+        D <= MAX_DECIMAL_PLACES,
+        "Decimal<D> supports at most 38 decimal places"
+    );
+
     /// The fractional multiplier: 10^D
-    pub const FRACTIONAL: u128 = pow10(D);
+    pub const FRACTIONAL: u128 = {
+        let () = Self::VALID_PRECISION;
+        pow10(D)
+    };
 
     /// Zero decimal value
-    pub const ZERO: Self = Self(Uint128::zero());
+    pub const ZERO: Self = Self::raw(0);
 
     /// One decimal value (1.0)
-    pub const ONE: Self = Self(Uint128::new(pow10(D)));
+    pub const ONE: Self = Self(Uint128::new(Self::FRACTIONAL));
 
     /// Maximum decimal value
-    pub const MAX: Self = Self(Uint128::MAX);
+    pub const MAX: Self = Self::raw(u128::MAX);
+
+    /// Smallest positive value, one atomic unit (`10^-D`); `0.000001` for `Decimal6`
+    pub const EPSILON: Self = Self::raw(1);
 
     /// Number of decimal places
     pub const DECIMAL_PLACES: u32 = D;
@@ -175,9 +283,24 @@ impl<const D: u32> Decimal<D> {
     /// let d = Decimal::<6>::raw(1_500_000); // 1.5
     /// ```
     pub const fn raw(atomics: u128) -> Self {
+        let () = Self::VALID_PRECISION;
         Self(Uint128::new(atomics))
     }
 
+    /// Create a Decimal from raw atomic units held in a `Uint128`, matching
+    /// `cosmwasm_std::Decimal::new`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let d = Decimal::<6>::new(Uint128::new(1_500_000)); // 1.5
+    /// ```
+    pub const fn new(atomics: Uint128) -> Self {
+        let () = Self::VALID_PRECISION;
+        Self(atomics)
+    }
+
     /// Create from atomics with specified decimal places, scaling as needed.
     ///
     /// # Example
@@ -188,11 +311,31 @@ impl<const D: u32> Decimal<D> {
     pub fn from_atomics(
         atomics: impl Into<Uint128>,
         decimal_places: u32,
+    ) -> Result<Self, CustomDecimalError> {
+        Self::checked_from_atomics(atomics, decimal_places)
+    }
+
+    /// Create from atomics with specified decimal places, never panicking.
+    ///
+    /// Scaling up returns `Overflow` if the result exceeds `MAX`. Scaling down
+    /// truncates, and any `decimal_places` is accepted: beyond
+    /// `D + MAX_DECIMAL_PLACES` every `u128` truncates to zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal};
+    /// assert_eq!(Decimal::<6>::checked_from_atomics(1_234_567u128, 7), Ok(Decimal::<6>::raw(123_456)));
+    /// assert_eq!(Decimal::<6>::checked_from_atomics(u128::MAX, 0), Err(CustomDecimalError::Overflow));
+    /// assert_eq!(Decimal::<6>::checked_from_atomics(u128::MAX, 100), Ok(Decimal::<6>::ZERO));
+    /// ```
+    pub fn checked_from_atomics(
+        atomics: impl Into<Uint128>,
+        decimal_places: u32,
     ) -> Result<Self, CustomDecimalError> {
         let atomics = atomics.into();
 
         Ok(match decimal_places.cmp(&D) {
-            std::cmp::Ordering::Less => {
+            core::cmp::Ordering::Less => {
                 // Scale up
                 let scale = pow10(D - decimal_places);
                 Self(
@@ -201,50 +344,155 @@ impl<const D: u32> Decimal<D> {
                         .map_err(|_| CustomDecimalError::Overflow)?,
                 )
             }
-            std::cmp::Ordering::Equal => Self(atomics),
-            std::cmp::Ordering::Greater => {
+            core::cmp::Ordering::Equal => Self(atomics),
+            core::cmp::Ordering::Greater if decimal_places - D > MAX_DECIMAL_PLACES => Self::ZERO,
+            core::cmp::Ordering::Greater => {
                 // Scale down
                 let scale = pow10(decimal_places - D);
-                Self(atomics.checked_div(Uint128::from(scale)).unwrap())
+                Self(atomics / Uint128::from(scale))
             }
         })
     }
 
+    /// Parse a decimal string, rejecting more than `D` fractional digits.
+    ///
+    /// This is the contract of `FromStr`. [`DeserializeMode::Strict`] is
+    /// slightly looser: it also accepts extra digits that are all zeros, so
+    /// 18-decimal `cosmwasm_std::Decimal` strings load. Plain lossy
+    /// `Deserialize` truncates any extra digits.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// assert!(Decimal6::from_str_exact("1.123456").is_ok());
+    /// assert!(Decimal6::from_str_exact("1.1234567").is_err());
+    /// ```
+    pub fn from_str_exact(s: &str) -> Result<Self, CustomDecimalError> {
+        s.parse()
+    }
+
+    /// Create from signed atomics, rejecting negative values with
+    /// `ConversionError`. Otherwise behaves like [`Decimal::checked_from_atomics`].
+    ///
+    /// Use [`SignedDecimal::from_signed_atomics`] to keep the sign.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// assert_eq!(Decimal6::try_from_atomics_i128(15, 1), Ok(Decimal6::raw(1_500_000)));
+    /// assert!(Decimal6::try_from_atomics_i128(-15, 1).is_err());
+    /// ```
+    pub fn try_from_atomics_i128(atomics: i128, decimal_places: u32) -> Result<Self, CustomDecimalError> {
+        let atomics = u128::try_from(atomics).map_err(|_| {
+            CustomDecimalError::ConversionError(
+                "negative atomics cannot be converted to Decimal".to_string(),
+            )
+        })?;
+        Self::checked_from_atomics(atomics, decimal_places)
+    }
+
+    /// Create from atomics with specified decimal places, rounding per `mode`
+    /// when `decimal_places > D`.
+    ///
+    /// Returns `Overflow` if scaling up exceeds `MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, RoundingMode};
+    /// let d = Decimal6::from_atomics_rounded(1_234_567_890u128, 9, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(d, Decimal6::raw(1_234_568));
+    /// ```
+    pub fn from_atomics_rounded(
+        atomics: impl Into<Uint128>,
+        decimal_places: u32,
+        mode: RoundingMode,
+    ) -> Result<Self, CustomDecimalError> {
+        let atomics = atomics.into();
+        if decimal_places <= D {
+            return Self::checked_from_atomics(atomics, decimal_places);
+        }
+
+        // Any u128 is below 10^39, so divisors past 10^77 (the largest power
+        // of ten in Uint256) all round the same way
+        let divisor = Uint256::from(10u8).pow((decimal_places - D).min(77));
+        let rounded = div_rounded(Uint256::from(atomics), divisor, mode);
+        // Dividing by at least 10 leaves room to round up
+        Ok(Self(Uint128::try_from(rounded).unwrap()))
+    }
+
+    /// Create from atomics with specified decimal places, returning
+    /// `PrecisionLoss` if scaling down would discard nonzero digits and
+    /// `Overflow` if scaling up exceeds `MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6};
+    /// assert_eq!(Decimal6::from_atomics_exact(1_234_000_000u128, 9), Ok(Decimal6::raw(1_234_000)));
+    /// assert_eq!(
+    ///     Decimal6::from_atomics_exact(1_234_567_890u128, 9),
+    ///     Err(CustomDecimalError::PrecisionLoss)
+    /// );
+    /// ```
+    pub fn from_atomics_exact(
+        atomics: impl Into<Uint128>,
+        decimal_places: u32,
+    ) -> Result<Self, CustomDecimalError> {
+        let atomics = atomics.into();
+        let truncated = Self::checked_from_atomics(atomics, decimal_places)?;
+        let rounded_up = Self::from_atomics_rounded(atomics, decimal_places, RoundingMode::Ceil)?;
+        if truncated != rounded_up {
+            return Err(CustomDecimalError::PrecisionLoss);
+        }
+        Ok(truncated)
+    }
+
     /// Create from a percentage value (0-100).
     ///
+    /// Computed as `from_ratio(x, 100)`, so it is exact for `D >= 2` and truncates
+    /// toward zero below that (e.g. `Decimal::<1>::percent(55)` is `0.5` and
+    /// `Decimal::<0>::percent(50)` is `0`).
+    ///
     /// # Example
     /// ```
     /// use cosmwasm_custom_decimal::Decimal;
     /// let d = Decimal::<6>::percent(50); // 0.5
     /// ```
     pub fn percent(x: u64) -> Self {
-        Self(Uint128::from(x) * Uint128::from(Self::FRACTIONAL / 100))
+        Self::from_ratio(x, 100u128)
     }
 
     /// Create from a permille value (0-1000).
     ///
+    /// Exact for `D >= 3`; truncates toward zero at lower precision.
+    ///
     /// # Example
     /// ```
     /// use cosmwasm_custom_decimal::Decimal;
     /// let d = Decimal::<6>::permille(125); // 0.125
     /// ```
     pub fn permille(x: u64) -> Self {
-        Self(Uint128::from(x) * Uint128::from(Self::FRACTIONAL / 1000))
+        Self::from_ratio(x, 1000u128)
     }
 
     /// Create from basis points (0-10000).
     ///
+    /// Exact for `D >= 4`; truncates toward zero at lower precision.
+    ///
     /// # Example
     /// ```
     /// use cosmwasm_custom_decimal::Decimal;
     /// let d = Decimal::<6>::bps(50); // 0.005
     /// ```
     pub fn bps(x: u64) -> Self {
-        Self(Uint128::from(x) * Uint128::from(Self::FRACTIONAL / 10000))
+        Self::from_ratio(x, 10000u128)
     }
 
     /// Create from a ratio of two values.
     ///
+    /// The result is truncated toward zero. When the denominator is a power of ten
+    /// `10^k` with `k <= D`, the ratio is computed exactly by scaling the numerator,
+    /// so no truncation can occur (e.g. `from_ratio(3, 1000)` is exactly `0.003`).
+    ///
     /// # Example
     /// ```
     /// use cosmwasm_custom_decimal::Decimal;
@@ -258,6 +506,17 @@ impl<const D: u32> Decimal<D> {
             panic!("Denominator must not be zero");
         }
 
+        // Fast exact path for power-of-ten denominators
+        if let Some(k) = pow10_exponent(denominator.u128()) {
+            if k <= D {
+                return Self(
+                    numerator
+                        .checked_mul(Uint128::from(pow10(D - k)))
+                        .expect("ratio overflow"),
+                );
+            }
+        }
+
         // Use Uint256 to prevent overflow
         let result = Uint256::from(numerator)
             .checked_mul(Uint256::from(Self::FRACTIONAL))
@@ -268,579 +527,4146 @@ impl<const D: u32> Decimal<D> {
         Self(Uint128::try_from(result).expect("ratio overflow"))
     }
 
-    // ========== Accessors ==========
+    /// Create from a ratio, rounding down. Same as [`Decimal::from_ratio`].
+    pub fn from_ratio_floor(numerator: impl Into<Uint128>, denominator: impl Into<Uint128>) -> Self {
+        Self::from_ratio(numerator, denominator)
+    }
 
-    /// Returns the raw atomic value.
-    pub const fn atomics(&self) -> u128 {
-        self.0.u128()
+    /// Create from a ratio, rounding up whenever the division leaves a
+    /// remainder, e.g. for minimum collateral requirements.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// assert_eq!(Decimal6::from_ratio_ceil(1u128, 3u128), Decimal6::raw(333_334));
+    /// assert_eq!(Decimal6::from_ratio_ceil(3u128, 2u128), Decimal6::raw(1_500_000));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the result exceeds `MAX`.
+    pub fn from_ratio_ceil(numerator: impl Into<Uint128>, denominator: impl Into<Uint128>) -> Self {
+        match Self::checked_from_ratio_ceil(numerator, denominator) {
+            Ok(value) => value,
+            Err(CustomDecimalError::DivisionByZero) => panic!("Denominator must not be zero"),
+            Err(_) => panic!("ratio overflow"),
+        }
     }
 
-    /// Returns the number of decimal places.
-    pub const fn decimal_places(&self) -> u32 {
-        D
+    /// Create from a ratio, rounding down. Returns `DivisionByZero` for a zero
+    /// denominator and `Overflow` if the result exceeds `MAX`.
+    pub fn checked_from_ratio_floor(
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Result<Self, CustomDecimalError> {
+        Self::checked_from_ratio_rounded(numerator.into(), denominator.into(), RoundingMode::Floor)
     }
 
-    /// Returns true if the value is zero.
-    pub fn is_zero(&self) -> bool {
-        self.0.is_zero()
+    /// Create from a ratio, rounding up. Returns `DivisionByZero` for a zero
+    /// denominator and `Overflow` if the result exceeds `MAX`.
+    pub fn checked_from_ratio_ceil(
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Result<Self, CustomDecimalError> {
+        Self::checked_from_ratio_rounded(numerator.into(), denominator.into(), RoundingMode::Ceil)
     }
 
-    // ========== Precision Conversion ==========
+    fn checked_from_ratio_rounded(
+        numerator: Uint128,
+        denominator: Uint128,
+        mode: RoundingMode,
+    ) -> Result<Self, CustomDecimalError> {
+        if denominator.is_zero() {
+            return Err(CustomDecimalError::DivisionByZero);
+        }
 
-    /// Convert to a different decimal precision.
+        let scaled = numerator.full_mul(Uint128::new(Self::FRACTIONAL));
+        let result = div_rounded(scaled, Uint256::from(denominator), mode);
+        Uint128::try_from(result)
+            .map(Self)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+
+    /// Create from a ratio in a `const` context, truncating toward zero.
+    ///
+    /// Unlike [`Decimal::from_ratio`], the intermediate `numerator * 10^D` is
+    /// computed in `u128` rather than `Uint256`, so this panics (a compile error
+    /// in `const` items) if `numerator * 10^D` overflows `u128`, even when the
+    /// final ratio would fit. It is meant for small constants such as fees.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
-    /// use std::str::FromStr;
-    /// let d6 = Decimal6::from_str("1.5").unwrap();
-    /// let d9: Decimal9 = d6.to_precision();
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// const FEE: Decimal6 = Decimal6::from_ratio_const(1, 3);
+    /// assert_eq!(FEE, Decimal6::raw(333_333));
     /// ```
-    pub fn to_precision<const D2: u32>(&self) -> Decimal<D2> {
-        if D == D2 {
-            // Same precision, just copy
-            Decimal(self.0)
-        } else if D < D2 {
-            // Scale up
-            let scale = pow10(D2 - D);
-            Decimal(self.0.checked_mul(Uint128::from(scale)).expect("precision conversion overflow"))
-        } else {
-            // Scale down (D > D2)
-            let scale = pow10(D - D2);
-            Decimal(self.0 / Uint128::from(scale))
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or `numerator * 10^D` overflows `u128`.
+    pub const fn from_ratio_const(numerator: u128, denominator: u128) -> Self {
+        if denominator == 0 {
+            panic!("Denominator must not be zero");
+        }
+        match numerator.checked_mul(Self::FRACTIONAL) {
+            Some(scaled) => Self::raw(scaled / denominator),
+            None => panic!("ratio overflow in from_ratio_const"),
         }
     }
 
-    /// Try to convert to a different decimal precision, returning None on overflow.
+    /// Create from a ratio, saturating at `MAX` instead of panicking.
+    ///
+    /// A zero denominator saturates to `MAX`, or gives `ZERO` when the numerator
+    /// is also zero.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
-    /// use std::str::FromStr;
-    /// let d6 = Decimal6::from_str("1.5").unwrap();
-    /// let d9: Option<Decimal9> = d6.try_to_precision();
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// assert_eq!(Decimal::<6>::saturating_from_ratio(3u128, 2u128), Decimal::<6>::raw(1_500_000));
+    /// assert_eq!(Decimal::<6>::saturating_from_ratio(u128::MAX, 1u128), Decimal::<6>::MAX);
     /// ```
-    pub fn try_to_precision<const D2: u32>(&self) -> Option<Decimal<D2>> {
-        if D == D2 {
-            Some(Decimal(self.0))
-        } else if D < D2 {
-            let scale = pow10(D2 - D);
-            self.0.checked_mul(Uint128::from(scale)).ok().map(Decimal)
-        } else {
-            let scale = pow10(D - D2);
-            Some(Decimal(self.0 / Uint128::from(scale)))
+    pub fn saturating_from_ratio(
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Self {
+        let numerator: Uint128 = numerator.into();
+        let denominator: Uint128 = denominator.into();
+
+        if denominator.is_zero() {
+            return if numerator.is_zero() { Self::ZERO } else { Self::MAX };
         }
+
+        let result = numerator.full_mul(Uint128::new(Self::FRACTIONAL)) / Uint256::from(denominator);
+        Uint128::try_from(result).map(Self).unwrap_or(Self::MAX)
     }
 
-    // ========== Checked Operations ==========
+    /// Create from a ratio, returning `PrecisionLoss` if a nonzero ratio rounds to
+    /// zero at precision `D`.
+    ///
+    /// This detects dust amounts collapsing to zero in low-precision types.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal};
+    /// assert_eq!(
+    ///     Decimal::<2>::checked_from_ratio_nonzero(1u128, 1000u128),
+    ///     Err(CustomDecimalError::PrecisionLoss)
+    /// );
+    /// assert!(Decimal::<6>::checked_from_ratio_nonzero(1u128, 1000u128).is_ok());
+    /// ```
+    pub fn checked_from_ratio_nonzero(
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Result<Self, CustomDecimalError> {
+        let numerator: Uint128 = numerator.into();
+        let denominator: Uint128 = denominator.into();
 
-    /// Checked addition. Returns `None` on overflow.
-    pub fn checked_add(self, other: Self) -> Option<Self> {
-        self.0.checked_add(other.0).ok().map(Self)
-    }
+        if denominator.is_zero() {
+            return Err(CustomDecimalError::DivisionByZero);
+        }
 
-    /// Checked subtraction. Returns `None` on underflow.
-    pub fn checked_sub(self, other: Self) -> Option<Self> {
-        self.0.checked_sub(other.0).ok().map(Self)
-    }
+        let result = Uint256::from(numerator)
+            .checked_mul(Uint256::from(Self::FRACTIONAL))
+            .map_err(|_| CustomDecimalError::Overflow)?
+            / Uint256::from(denominator);
+        let result = Uint128::try_from(result).map_err(|_| CustomDecimalError::Overflow)?;
 
-    /// Checked multiplication. Returns `None` on overflow.
-    pub fn checked_mul(self, other: Self) -> Option<Self> {
-        let result = Uint256::from(self.0)
-            .checked_mul(Uint256::from(other.0)).ok()?
-            .checked_div(Uint256::from(Self::FRACTIONAL)).ok()?;
+        if result.is_zero() && !numerator.is_zero() {
+            return Err(CustomDecimalError::PrecisionLoss);
+        }
 
-        Uint128::try_from(result).ok().map(Self)
+        Ok(Self(result))
     }
 
-    /// Checked division. Returns `None` on division by zero or overflow.
-    pub fn checked_div(self, other: Self) -> Option<Self> {
-        if other.0.is_zero() {
-            return None;
-        }
+    /// Parse a decimal string with an optional unit suffix.
+    ///
+    /// Each unit maps to a power-of-ten exponent applied to the number, e.g.
+    /// `("k", 3)` turns `"1.5k"` into `1500` and `("m", -3)` turns `"5m"` into
+    /// `0.005`. The longest matching suffix wins. A string without a suffix is
+    /// parsed as a plain number; an unrecognized suffix is a `ParseError`.
+    ///
+    /// Errors if the scaled value needs more than `D` decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let units = [("k", 3), ("M", 6), ("m", -3)];
+    /// let d = Decimal::<6>::from_str_with_units("1.5k", &units).unwrap();
+    /// assert_eq!(d, Decimal::<6>::from(1500u64));
+    /// ```
+    pub fn from_str_with_units(s: &str, units: &[(&str, i32)]) -> Result<Self, CustomDecimalError> {
+        let unit = units
+            .iter()
+            .filter(|(unit, _)| !unit.is_empty() && s.ends_with(unit))
+            .max_by_key(|(unit, _)| unit.len());
+
+        let (number, exp) = match unit {
+            Some((unit, exp)) => (s[..s.len() - unit.len()].trim_end(), *exp),
+            None if s.ends_with(|c: char| c.is_ascii_digit()) => (s, 0),
+            None => {
+                return Err(CustomDecimalError::ParseError(format!(
+                    "Unknown unit suffix: {}",
+                    s
+                )))
+            }
+        };
 
-        let numerator = Uint256::from(self.0)
-            .checked_mul(Uint256::from(Self::FRACTIONAL)).ok()?;
-        let result = numerator.checked_div(Uint256::from(other.0)).ok()?;
+        let (integer, fractional) = match number.split_once('.') {
+            Some((integer, fractional)) => (integer, Some(fractional)),
+            None => (number, None),
+        };
 
-        Uint128::try_from(result).ok().map(Self)
+        Self::from_parts_with_exponent(integer, fractional, exp)
     }
 
-    /// Checked remainder. Returns `None` on division by zero.
-    pub fn checked_rem(self, other: Self) -> Option<Self> {
-        self.0.checked_rem(other.0).ok().map(Self)
-    }
+    /// Build from decimal digit strings `integer[.fractional]` multiplied by `10^exp`.
+    ///
+    /// Errors if any digits remain below `10^-D` after shifting.
+    fn from_parts_with_exponent(
+        integer: &str,
+        fractional: Option<&str>,
+        exp: i32,
+    ) -> Result<Self, CustomDecimalError> {
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(integer) {
+            return Err(CustomDecimalError::ParseError(format!("Invalid integer: {}", integer)));
+        }
+        if let Some(fractional) = fractional {
+            if !is_digits(fractional) {
+                return Err(CustomDecimalError::ParseError(format!(
+                    "Invalid fractional: {}",
+                    fractional
+                )));
+            }
+        }
 
-    /// Checked power. Returns `None` on overflow.
-    pub fn checked_pow(self, exp: u32) -> Option<Self> {
-        // Special cases
-        if exp == 0 {
-            return Some(Self::ONE);
+        // Strip zeros that carry no value, folding trailing ones into the shift
+        let fractional = fractional.unwrap_or("").trim_end_matches('0');
+        let digits = format!("{}{}", integer, fractional);
+        let significant = digits.trim_start_matches('0').trim_end_matches('0');
+        if significant.is_empty() {
+            return Ok(Self::ZERO);
         }
-        if exp == 1 {
-            return Some(self);
+        let trailing_zeros = digits.len() - digits.trim_end_matches('0').len();
+
+        // Power of ten that maps the significant digits onto D-decimal atomics
+        let shift =
+            i64::from(D) + i64::from(exp) - fractional.len() as i64 + trailing_zeros as i64;
+        if shift < 0 {
+            // The last significant digit is nonzero, so it would fall below 10^-D
+            return Err(CustomDecimalError::ParseError(format!(
+                "Too many decimal places (max {})",
+                D
+            )));
         }
-        if self.is_zero() {
-            return Some(Self::ZERO);
+        if shift > 38 {
+            return Err(CustomDecimalError::Overflow);
         }
 
-        // Use repeated multiplication with overflow checking
-        let mut result = self;
-        for _ in 1..exp {
-            result = result.checked_mul(self)?;
-        }
-        Some(result)
+        let atomics = significant
+            .parse::<u128>()
+            .ok()
+            .and_then(|mantissa| mantissa.checked_mul(pow10(shift as u32)))
+            .ok_or(CustomDecimalError::Overflow)?;
+
+        Ok(Self(Uint128::new(atomics)))
     }
 
-    // ========== Saturating Operations ==========
+    // ========== Accessors ==========
 
-    /// Saturating addition. Returns `MAX` on overflow.
-    pub fn saturating_add(self, other: Self) -> Self {
-        Self(self.0.saturating_add(other.0))
+    /// Returns the raw atomic value.
+    pub const fn atomics(&self) -> u128 {
+        self.0.u128()
     }
 
-    /// Saturating subtraction. Returns `ZERO` on underflow.
-    pub fn saturating_sub(self, other: Self) -> Self {
-        Self(self.0.saturating_sub(other.0))
+    /// Returns the raw atomic value as a `Uint128`.
+    pub const fn atomics_uint128(&self) -> Uint128 {
+        self.0
     }
 
-    /// Saturating multiplication. Returns `MAX` on overflow.
-    pub fn saturating_mul(self, other: Self) -> Self {
-        match self.checked_mul(other) {
-            Some(result) => result,
-            None => Self::MAX,
+    /// Returns the number of decimal places.
+    pub const fn decimal_places(&self) -> u32 {
+        D
+    }
+
+    /// Returns the numerator of the fraction `atomics / 10^D`, i.e. the atomics.
+    pub const fn numerator(&self) -> Uint128 {
+        self.0
+    }
+
+    /// Returns the denominator of the fraction `atomics / 10^D`, i.e. `10^D`.
+    pub const fn denominator(&self) -> Uint128 {
+        Uint128::new(Self::FRACTIONAL)
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns true if the value is exactly one.
+    pub fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+
+    /// Returns true if the value has no fractional part.
+    pub fn is_integer(&self) -> bool {
+        self.0.u128() % Self::FRACTIONAL == 0
+    }
+
+    /// Returns true if the value is strictly greater than zero.
+    ///
+    /// `Decimal<D>` is unsigned, so this is false only for zero. It mirrors
+    /// [`SignedDecimal::is_positive`] so sign-aware code works with both types.
+    pub fn is_positive(&self) -> bool {
+        !self.is_zero()
+    }
+
+    /// Returns `ZERO` for zero and `ONE` otherwise.
+    ///
+    /// The unsigned counterpart of [`SignedDecimal::signum`], which can also
+    /// return `-1`.
+    pub fn signum(self) -> Self {
+        if self.is_zero() {
+            Self::ZERO
+        } else {
+            Self::ONE
         }
     }
 
-    // ========== Rounding & Math ==========
+    // ========== Precision Conversion ==========
 
-    /// Returns the largest integer less than or equal to this value.
+    /// Convert to a different decimal precision.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
     /// use std::str::FromStr;
-    /// let d = Decimal::<6>::from_str("1.7").unwrap();
-    /// assert_eq!(d.floor(), Decimal::<6>::from_str("1.0").unwrap());
+    /// let d6 = Decimal6::from_str("1.5").unwrap();
+    /// let d9: Decimal9 = d6.to_precision();
     /// ```
-    pub fn floor(self) -> Self {
-        Self(
-            (self.0.u128() / Self::FRACTIONAL * Self::FRACTIONAL).into(),
-        )
+    pub fn to_precision<const D2: u32>(&self) -> Decimal<D2> {
+        self.checked_to_precision().expect("precision conversion overflow")
     }
 
-    /// Returns the smallest integer greater than or equal to this value.
+    /// Try to convert to a different decimal precision, returning None on overflow.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
     /// use std::str::FromStr;
-    /// let d = Decimal::<6>::from_str("1.1").unwrap();
-    /// assert_eq!(d.ceil(), Decimal::<6>::from_str("2.0").unwrap());
+    /// let d6 = Decimal6::from_str("1.5").unwrap();
+    /// let d9: Option<Decimal9> = d6.try_to_precision();
     /// ```
-    pub fn ceil(self) -> Self {
-        let floor = self.floor();
-        if self == floor {
-            floor
+    pub fn try_to_precision<const D2: u32>(&self) -> Option<Decimal<D2>> {
+        self.checked_to_precision().ok()
+    }
+
+    /// Convert to a different decimal precision, returning
+    /// `PrecisionConversionOverflow` when scaling up exceeds `Uint128`.
+    ///
+    /// Scaling down truncates toward zero, like `to_precision`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6, Decimal18};
+    /// let err = Decimal6::MAX.checked_to_precision::<18>().unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     CustomDecimalError::PrecisionConversionOverflow { from_decimals: 6, to_decimals: 18 }
+    /// );
+    /// let d18: Decimal18 = Decimal6::ONE.checked_to_precision().unwrap();
+    /// assert_eq!(d18, Decimal18::ONE);
+    /// ```
+    pub fn checked_to_precision<const D2: u32>(&self) -> Result<Decimal<D2>, CustomDecimalError> {
+        let (scale, scale_up) = scale_between::<D, D2>();
+        if scale_up {
+            self.0
+                .checked_mul(Uint128::from(scale))
+                .map(Decimal)
+                .map_err(|_| CustomDecimalError::PrecisionConversionOverflow {
+                    from_decimals: D,
+                    to_decimals: D2,
+                })
         } else {
-            floor + Self::ONE
+            // Scale down (D > D2)
+            Ok(Decimal(self.0 / Uint128::from(scale)))
         }
     }
 
-    /// Square root using Decimal's sqrt internally (converts to/from).
+    /// Convert every value to precision `D2`, truncating when scaling down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if scaling up overflows, like `to_precision`. Use
+    /// [`Decimal::try_convert_slice`] to handle it.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_custom_decimal::{Decimal18, Decimal6};
+    /// let prices = [Decimal6::ONE, Decimal6::percent(50)];
+    /// let wide: Vec<Decimal18> = Decimal6::convert_slice(&prices);
+    /// assert_eq!(wide, [Decimal18::ONE, Decimal18::percent(50)]);
+    /// ```
+    pub fn convert_slice<const D2: u32>(values: &[Self]) -> Vec<Decimal<D2>> {
+        Self::try_convert_slice(values).expect("precision conversion overflow")
+    }
+
+    /// Convert every value to precision `D2`, stopping at the first overflow
+    /// with `SliceConversionOverflow` naming its index.
+    pub fn try_convert_slice<const D2: u32>(
+        values: &[Self],
+    ) -> Result<Vec<Decimal<D2>>, CustomDecimalError> {
+        let (scale, scale_up) = scale_between::<D, D2>();
+        let scale = Uint128::new(scale);
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                if !scale_up {
+                    return Ok(Decimal(value.0 / scale));
+                }
+                value.0.checked_mul(scale).map(Decimal).map_err(|_| {
+                    CustomDecimalError::SliceConversionOverflow {
+                        index,
+                        from_decimals: D,
+                        to_decimals: D2,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Convert to a different decimal precision, rounding per `mode` when scaling down.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow when scaling up, like `to_precision`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9, RoundingMode};
     /// use std::str::FromStr;
-    /// let d = Decimal::<6>::from_str("4.0").unwrap();
-    /// assert_eq!(d.sqrt(), Decimal::<6>::from_str("2.0").unwrap());
+    /// let d9 = Decimal9::from_str("1.1234565").unwrap();
+    /// let d6: Decimal6 = d9.to_precision_with(RoundingMode::HalfUp);
+    /// assert_eq!(d6, Decimal6::from_str("1.123457").unwrap());
     /// ```
-    pub fn sqrt(self) -> Self {
-        // Convert to cosmwasm_std::Decimal (18 decimals), use its sqrt, convert back
-        let decimal: StdDecimal = self.into();
-        let sqrt_decimal = decimal.sqrt();
-        sqrt_decimal.into()
+    pub fn to_precision_with<const D2: u32>(&self, mode: RoundingMode) -> Decimal<D2> {
+        if D <= D2 {
+            return self.to_precision();
+        }
+
+        let scale = Uint256::from(pow10(D - D2));
+        let rounded = div_rounded(Uint256::from(self.0), scale, mode);
+        // Rounding up MAX can exceed the source value but never Uint128 after dividing by >= 10
+        Decimal(Uint128::try_from(rounded).expect("precision conversion overflow"))
     }
 
-    /// Power function.
+    /// Widen to a precision with at least as many decimal places.
+    ///
+    /// Widening never loses precision; the only failure mode is overflow when the
+    /// scaled atomics exceed `Uint128`, which happens for values above
+    /// `Uint128::MAX / 10^(D2 - D)` atomics (e.g. beyond ~3.4e20 when widening
+    /// `Decimal6` to `Decimal18`).
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow. Use [`Decimal::try_to_precision`] to handle it.
     ///
     /// # Example
     /// ```
-    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
     /// use std::str::FromStr;
-    /// let d = Decimal::<6>::from_str("2.0").unwrap();
-    /// assert_eq!(d.pow(3), Decimal::<6>::from_str("8.0").unwrap());
+    /// let d6 = Decimal6::from_str("1.5").unwrap();
+    /// let d9: Decimal9 = d6.widen();
+    /// assert_eq!(d9.atomics(), 1_500_000_000);
     /// ```
-    pub fn pow(self, exp: u32) -> Self {
-        self.checked_pow(exp).expect("overflow in pow")
+    ///
+    /// Narrowing through `widen` is rejected at compile time:
+    /// ```compile_fail
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
+    /// let d6: Decimal6 = Decimal9::ONE.widen();
+    /// ```
+    pub fn widen<const D2: u32>(self) -> Decimal<D2> {
+        const { assert!(D2 >= D, "widen requires D2 >= D") };
+        let scale = pow10(D2 - D);
+        Decimal(
+            self.0
+                .checked_mul(Uint128::from(scale))
+                .expect("precision conversion overflow"),
+        )
     }
 
-    // ========== Comparisons ==========
+    /// Narrow to a precision with at most as many decimal places.
+    ///
+    /// Extra decimal places are truncated toward zero. Narrowing cannot overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal18};
+    /// use std::str::FromStr;
+    /// let d18 = Decimal18::from_str("1.123456789").unwrap();
+    /// let d6: Decimal6 = d18.narrow();
+    /// assert_eq!(d6.to_string(), "1.123456");
+    /// ```
+    ///
+    /// Widening through `narrow` is rejected at compile time:
+    /// ```compile_fail
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
+    /// let d9: Decimal9 = Decimal6::ONE.narrow();
+    /// ```
+    pub fn narrow<const D2: u32>(self) -> Decimal<D2> {
+        const { assert!(D2 <= D, "narrow requires D2 <= D") };
+        let scale = pow10(D - D2);
+        Decimal(self.0 / Uint128::from(scale))
+    }
 
-    /// Returns the minimum of two values.
-    pub fn min(self, other: Self) -> Self {
-        if self < other {
-            self
+    /// Try to convert from `cosmwasm_std::Decimal`, returning `Overflow` if scaling
+    /// up to `D > 18` decimals exceeds `Uint128`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal};
+    /// use cosmwasm_std::Decimal as StdDecimal;
+    /// assert_eq!(
+    ///     Decimal::<20>::try_from_std_decimal(StdDecimal::MAX),
+    ///     Err(CustomDecimalError::Overflow)
+    /// );
+    /// ```
+    pub fn try_from_std_decimal(decimal: StdDecimal) -> Result<Self, CustomDecimalError> {
+        // StdDecimal stores as Uint128 with 18 decimals
+        let atomics = decimal.atomics();
+        if D >= 18 {
+            // Scale up (rare case)
+            let scale = pow10(D - 18);
+            atomics
+                .checked_mul(Uint128::from(scale))
+                .map(Self)
+                .map_err(|_| CustomDecimalError::Overflow)
         } else {
-            other
+            // Scale down (common case)
+            let scale = pow10(18 - D);
+            Ok(Self(atomics / Uint128::from(scale)))
         }
     }
 
-    /// Returns the maximum of two values.
-    pub fn max(self, other: Self) -> Self {
-        if self > other {
-            self
+    /// Former name of [`Decimal::try_from_std_decimal`].
+    #[deprecated(note = "use `try_from_std_decimal`, which pairs with `try_to_std_decimal`")]
+    pub fn try_from_std(decimal: StdDecimal) -> Result<Self, CustomDecimalError> {
+        Self::try_from_std_decimal(decimal)
+    }
+
+    /// Try to convert to `cosmwasm_std::Decimal`, returning `Overflow` if scaling
+    /// up from `D < 18` decimals exceeds `Uint128`.
+    ///
+    /// Scaling down from `D > 18` truncates and cannot fail.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6};
+    /// assert_eq!(Decimal6::MAX.try_to_std_decimal(), Err(CustomDecimalError::Overflow));
+    /// ```
+    pub fn try_to_std_decimal(self) -> Result<StdDecimal, CustomDecimalError> {
+        if D >= 18 {
+            let scale = pow10(D - 18);
+            Ok(StdDecimal::new(self.0 / Uint128::from(scale)))
         } else {
-            other
+            let scale = pow10(18 - D);
+            self.0
+                .checked_mul(Uint128::from(scale))
+                .map(StdDecimal::new)
+                .map_err(|_| CustomDecimalError::Overflow)
         }
     }
 
-    /// Returns the absolute difference between two values.
-    pub fn abs_diff(self, other: Self) -> Self {
-        if self > other {
-            self - other
-        } else {
-            other - self
+    /// Convert from `cosmwasm_std::Decimal`, rounding per `mode` when scaling
+    /// down from 18 to `D < 18` decimals instead of truncating like `From`.
+    ///
+    /// # Panics
+    ///
+    /// For `D > 18` the atomics are scaled up, which panics on overflow like `From`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, RoundingMode};
+    /// use cosmwasm_std::Decimal as StdDecimal;
+    /// use std::str::FromStr;
+    /// let std = StdDecimal::from_str("1.1234565").unwrap();
+    /// let d = Decimal6::from_std_decimal_rounded(std, RoundingMode::HalfUp);
+    /// assert_eq!(d, Decimal6::from_str("1.123457").unwrap());
+    /// ```
+    pub fn from_std_decimal_rounded(decimal: StdDecimal, mode: RoundingMode) -> Self {
+        if D >= 18 {
+            return Self::from(decimal);
         }
+
+        let scale = Uint256::from(pow10(18 - D));
+        let rounded = div_rounded(Uint256::from(decimal.atomics()), scale, mode);
+        // Dividing by at least 10 leaves room for the rounding increment
+        Self(Uint128::try_from(rounded).expect("StdDecimal conversion overflow"))
     }
 
-    // ========== Conversions to Uint128 ==========
+    // ========== Checked Operations ==========
 
-    /// Convert to `Uint128` by flooring.
-    pub fn to_uint_floor(self) -> Uint128 {
-        self.0 / Uint128::from(Self::FRACTIONAL)
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).ok().map(Self)
     }
 
-    /// Convert to `Uint128` by ceiling.
-    pub fn to_uint_ceil(self) -> Uint128 {
-        self.ceil().to_uint_floor()
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).ok().map(Self)
     }
 
-    /// Multiply by `Uint128` and floor the result.
-    pub fn mul_uint_floor(self, rhs: Uint128) -> Uint128 {
-        (self * rhs).checked_div(Uint128::one()).unwrap()
+    /// Subtract into a [`SignedDecimal`], so a larger `other` gives a negative
+    /// result instead of underflowing.
+    ///
+    /// Both magnitudes are at most `MAX` and the signed range is symmetric, so
+    /// this currently always returns `Some`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// let diff = Decimal6::from(2u64).checked_signed_sub(Decimal6::from(5u64)).unwrap();
+    /// assert!(diff.is_negative());
+    /// assert_eq!(diff.magnitude(), Decimal6::from(3u64));
+    /// ```
+    pub fn checked_signed_sub(self, other: Self) -> Option<SignedDecimal<D>> {
+        SignedDecimal::from(self).checked_sub(SignedDecimal::from(other))
     }
 
-    /// Multiply by `Uint128` and ceil the result.
-    pub fn mul_uint_ceil(self, rhs: Uint128) -> Uint128 {
-        let product = self * rhs;
-        let floored = product / Uint128::one();
-        if product % Uint128::one() == Uint128::zero() {
-            floored
+    /// Checked negation. Only zero can be negated without leaving the unsigned
+    /// range, so this is `Some(ZERO)` for zero and `None` otherwise. Unlike
+    /// `Neg`, it never panics.
+    pub fn checked_neg(self) -> Option<Self> {
+        if self.is_zero() {
+            Some(self)
         } else {
-            floored + Uint128::one()
+            None
         }
     }
-}
 
-// ========== Type Conversions ==========
+    /// Add raw atomics at the same scale. Returns `None` on overflow.
+    ///
+    /// Operates directly on the atomic representation, e.g. for interop with
+    /// external fixed-point values that share the `10^D` scale.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let d = Decimal::<6>::ONE.add_atomics(500_000).unwrap();
+    /// assert_eq!(d, Decimal::<6>::raw(1_500_000));
+    /// ```
+    pub fn add_atomics(self, atomics: u128) -> Option<Self> {
+        self.0.u128().checked_add(atomics).map(Self::raw)
+    }
 
-/// From Uint128 (treats as integer, e.g., 5 -> 5.0)
-impl<const D: u32> From<Uint128> for Decimal<D> {
-    fn from(value: Uint128) -> Self {
-        Self(value * Uint128::from(Self::FRACTIONAL))
+    /// Subtract raw atomics at the same scale. Returns `None` on underflow.
+    pub fn sub_atomics(self, atomics: u128) -> Option<Self> {
+        self.0.u128().checked_sub(atomics).map(Self::raw)
     }
-}
 
-/// From u128 (treats as integer)
-impl<const D: u32> From<u128> for Decimal<D> {
-    fn from(value: u128) -> Self {
-        Self::from(Uint128::from(value))
+    /// Add a whole number. Returns `None` if scaling `rhs` or the sum overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let d = Decimal::<6>::raw(1_500_000).checked_add_uint(Uint128::new(2)).unwrap();
+    /// assert_eq!(d, Decimal::<6>::raw(3_500_000));
+    /// ```
+    pub fn checked_add_uint(self, rhs: Uint128) -> Option<Self> {
+        let scaled = rhs.checked_mul(Uint128::from(Self::FRACTIONAL)).ok()?;
+        self.0.checked_add(scaled).ok().map(Self)
     }
-}
 
-/// From u64 (treats as integer)
-impl<const D: u32> From<u64> for Decimal<D> {
-    fn from(value: u64) -> Self {
-        Self::from(Uint128::from(value))
+    /// Subtract a whole number. Returns `None` on underflow.
+    pub fn checked_sub_uint(self, rhs: Uint128) -> Option<Self> {
+        let scaled = rhs.checked_mul(Uint128::from(Self::FRACTIONAL)).ok()?;
+        self.0.checked_sub(scaled).ok().map(Self)
     }
-}
 
-/// Convert from cosmwasm_std::Decimal (truncates precision from 18 to D decimals)
-impl<const D: u32> From<StdDecimal> for Decimal<D> {
-    fn from(decimal: StdDecimal) -> Self {
-        // StdDecimal stores as Uint128 with 18 decimals
-        let atomics = decimal.atomics();
-        if D >= 18 {
-            // Scale up (rare case)
-            let scale = pow10(D - 18);
-            Self(atomics * Uint128::from(scale))
-        } else {
-            // Scale down (common case)
-            let scale = pow10(18 - D);
-            Self(atomics / Uint128::from(scale))
+    /// Divide by a whole number, truncating. Returns `None` if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// let d = Decimal6::from(10u64).checked_div_uint(Uint128::new(3)).unwrap();
+    /// assert_eq!(d, Decimal6::raw(3_333_333));
+    /// assert_eq!(Decimal6::ONE.checked_div_uint(Uint128::zero()), None);
+    /// ```
+    pub fn checked_div_uint(self, rhs: Uint128) -> Option<Self> {
+        self.0.checked_div(rhs).ok().map(Self)
+    }
+
+    /// Remainder after dividing by a whole number, matching
+    /// `self % Decimal::from(rhs)`. Returns `None` if `rhs` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// let d = Decimal6::raw(7_500_000).checked_rem_uint(Uint128::new(2)).unwrap();
+    /// assert_eq!(d, Decimal6::raw(1_500_000)); // 7.5 % 2 = 1.5
+    /// ```
+    pub fn checked_rem_uint(self, rhs: Uint128) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        // A divisor above MAX leaves any value unchanged
+        match rhs.checked_mul(Uint128::from(Self::FRACTIONAL)) {
+            Ok(scaled) => Some(Self(self.0 % scaled)),
+            Err(_) => Some(self),
         }
     }
-}
 
-/// Convert to cosmwasm_std::Decimal (scales from D to 18 decimals)
-impl<const D: u32> From<Decimal<D>> for StdDecimal {
-    fn from(custom: Decimal<D>) -> Self {
-        if D >= 18 {
-            // Scale down (rare case)
-            let scale = pow10(D - 18);
-            StdDecimal::new(custom.0 / Uint128::from(scale))
-        } else {
-            // Scale up (common case)
-            let scale = pow10(18 - D);
-            let scaled = custom.0.checked_mul(Uint128::from(scale)).unwrap();
-            StdDecimal::new(scaled)
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let result = Uint256::from(self.0)
+            .checked_mul(Uint256::from(other.0)).ok()?
+            .checked_div(Uint256::from(Self::FRACTIONAL)).ok()?;
+
+        Uint128::try_from(result).ok().map(Self)
+    }
+
+    /// Multiply without narrowing, returning the exact (truncated) product as a
+    /// [`Decimal256Custom<D>`]. This never overflows: the product of two
+    /// `Uint128` atomics always fits in `Uint256`.
+    ///
+    /// Like `Uint128::full_mul`, this keeps intermediate results wide so they
+    /// can be narrowed once at the end of a computation.
+    pub fn full_mul(self, other: Self) -> Decimal256Custom<D> {
+        Decimal256Custom::new(self.0.full_mul(other.0) / Uint256::from(Self::FRACTIONAL))
+    }
+
+    /// Checked division. Returns `None` on division by zero or overflow.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0.is_zero() {
+            return None;
         }
+
+        let numerator = Uint256::from(self.0)
+            .checked_mul(Uint256::from(Self::FRACTIONAL)).ok()?;
+        let result = numerator.checked_div(Uint256::from(other.0)).ok()?;
+
+        Uint128::try_from(result).ok().map(Self)
     }
-}
 
-/// Convert to Decimal256
-impl<const D: u32> From<Decimal<D>> for Decimal256 {
-    fn from(custom: Decimal<D>) -> Self {
-        // First convert to StdDecimal, then to Decimal256
-        let decimal: StdDecimal = custom.into();
-        decimal.into()
+    /// Reciprocal `1 / self`, truncated to `D` places exactly like `ONE / self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on zero, matching the `/` operator, or if the reciprocal exceeds
+    /// `MAX` (only possible for `D > 19`).
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// assert_eq!(Decimal::<6>::percent(25).inv(), Decimal::<6>::from(4u64));
+    /// ```
+    pub fn inv(self) -> Self {
+        if self.0.is_zero() {
+            panic!("Division by zero");
+        }
+        self.checked_inv().expect("division result exceeds Uint128 range")
+    }
+
+    /// Reciprocal `1 / self`, or `None` for zero or if the result exceeds `MAX`.
+    pub fn checked_inv(self) -> Option<Self> {
+        Self::ONE.checked_div(self)
+    }
+
+    /// Full-width product of the atomics, without narrowing.
+    ///
+    /// The returned `Uint256` is scaled by `10^(2 * D)`, i.e. it holds the product
+    /// with `2 * D` decimal places. Dividing it by `FRACTIONAL` gives the same
+    /// result as `checked_mul`. Chain further operations on the wide value and
+    /// narrow once at the end to avoid intermediate truncation.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint256;
+    /// let a = Decimal::<2>::raw(150); // 1.5
+    /// let b = Decimal::<2>::raw(250); // 2.5
+    /// assert_eq!(a.mul_raw_256(b), Uint256::from(37_500u128)); // 3.7500
+    /// ```
+    pub fn mul_raw_256(self, other: Self) -> Uint256 {
+        // Cannot overflow: (2^128 - 1)^2 < 2^256
+        Uint256::from(self.0) * Uint256::from(other.0)
+    }
+
+    /// Full-width quotient, without narrowing. Returns `None` on division by zero
+    /// or if the scaled numerator exceeds `Uint256`.
+    ///
+    /// The returned `Uint256` is scaled by `10^(2 * D)` (the same scale as
+    /// [`Decimal::mul_raw_256`]), carrying `D` extra decimal places of the
+    /// quotient beyond what `checked_div` keeps.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint256;
+    /// let one = Decimal::<2>::ONE;
+    /// let three = Decimal::<2>::raw(300);
+    /// assert_eq!(one.div_raw_256(three), Some(Uint256::from(3_333u128))); // 0.3333
+    /// ```
+    pub fn div_raw_256(self, other: Self) -> Option<Uint256> {
+        if other.0.is_zero() {
+            return None;
+        }
+
+        let scale = Uint256::from(Self::FRACTIONAL)
+            .checked_mul(Uint256::from(Self::FRACTIONAL)).ok()?;
+        let numerator = Uint256::from(self.0).checked_mul(scale).ok()?;
+        Some(numerator / Uint256::from(other.0))
+    }
+
+    /// Checked division with explicit rounding. Returns `None` on division by zero
+    /// or overflow.
+    ///
+    /// The remainder of the `Uint256` intermediate decides the rounding, so
+    /// `Truncate`/`Floor` match `checked_div` exactly.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal, RoundingMode};
+    /// let two = Decimal::<6>::from(2u64);
+    /// let three = Decimal::<6>::from(3u64);
+    /// assert_eq!(two.checked_div_with(three, RoundingMode::HalfUp), Some(Decimal::<6>::raw(666_667)));
+    /// ```
+    pub fn checked_div_with(self, other: Self, mode: RoundingMode) -> Option<Self> {
+        if other.0.is_zero() {
+            return None;
+        }
+
+        let numerator = Uint256::from(self.0)
+            .checked_mul(Uint256::from(Self::FRACTIONAL)).ok()?;
+        let result = div_rounded(numerator, Uint256::from(other.0), mode);
+
+        Uint128::try_from(result).ok().map(Self)
+    }
+
+    /// Compute `self * numerator / denominator` in a single `Uint256` step,
+    /// truncating once at the end.
+    ///
+    /// This avoids the intermediate rounding of chaining `/` and `*`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the result exceeds `MAX`.
+    /// Use [`Decimal::checked_multiply_ratio`] to handle these cases.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let d = Decimal::<6>::ONE.multiply_ratio(3u128, 3u128);
+    /// assert_eq!(d, Decimal::<6>::ONE);
+    /// ```
+    pub fn multiply_ratio(
+        self,
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Self {
+        match self.checked_multiply_ratio(numerator, denominator) {
+            Ok(value) => value,
+            Err(CustomDecimalError::DivisionByZero) => panic!("Denominator must not be zero"),
+            Err(_) => panic!("multiply_ratio overflow"),
+        }
+    }
+
+    /// Compute `self * numerator / denominator`, returning `DivisionByZero` for a
+    /// zero denominator and `Overflow` if the result exceeds `MAX`.
+    pub fn checked_multiply_ratio(
+        self,
+        numerator: impl Into<Uint128>,
+        denominator: impl Into<Uint128>,
+    ) -> Result<Self, CustomDecimalError> {
+        let numerator: Uint128 = numerator.into();
+        let denominator: Uint128 = denominator.into();
+        if denominator.is_zero() {
+            return Err(CustomDecimalError::DivisionByZero);
+        }
+
+        // u128 * u128 always fits in Uint256
+        let result = Uint256::from(self.0) * Uint256::from(numerator) / Uint256::from(denominator);
+        Uint128::try_from(result)
+            .map(Self)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+
+    /// The fee charged on `self` at `rate`, i.e. `self * rate` rounded down.
+    ///
+    /// Computed from the full `Uint256` product, so it never overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate > ONE`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// let amount = Decimal6::from(100u64);
+    /// assert_eq!(amount.fee_amount(Decimal6::percent(3)), Decimal6::from(3u64));
+    /// ```
+    pub fn fee_amount(self, rate: Self) -> Self {
+        assert!(rate <= Self::ONE, "fee rate must not exceed ONE");
+        // rate <= ONE, so the fee is at most self and fits
+        let fee = self.0.full_mul(rate.0) / Uint256::from(Self::FRACTIONAL);
+        Self(Uint128::try_from(fee).unwrap())
+    }
+
+    /// `self` net of the fee at `rate`, i.e. `self * (ONE - rate)`.
+    ///
+    /// Computed as `self - self.fee_amount(rate)`, so the fee is rounded once
+    /// (down) and the two always sum back to `self`:
+    /// `x.apply_fee(r) + x.fee_amount(r) == x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate > ONE`. Use [`Decimal::checked_apply_fee`] to handle it.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// let amount = Decimal6::from(100u64);
+    /// assert_eq!(amount.apply_fee(Decimal6::percent(3)), Decimal6::from(97u64));
+    /// ```
+    pub fn apply_fee(self, rate: Self) -> Self {
+        self.checked_apply_fee(rate).expect("fee rate must not exceed ONE")
+    }
+
+    /// Like [`Decimal::apply_fee`], returning `RangeExceeded` if `rate > ONE`.
+    pub fn checked_apply_fee(self, rate: Self) -> Result<Self, CustomDecimalError> {
+        if rate > Self::ONE {
+            return Err(CustomDecimalError::RangeExceeded);
+        }
+        Ok(self - self.fee_amount(rate))
+    }
+
+    /// Checked remainder. Returns `None` on division by zero.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        self.0.checked_rem(other.0).ok().map(Self)
+    }
+
+    /// Whole-number quotient and remainder in one division, so that
+    /// `q * other + r == self` exactly (`r` is the same as `self % other`).
+    ///
+    /// # Panics
+    ///
+    /// Panics on division by zero or if the quotient exceeds `MAX`.
+    /// Use [`Decimal::checked_div_rem`] to handle these cases.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let total = Decimal::<6>::from_str("10.5").unwrap();
+    /// let period = Decimal::<6>::from_str("3.0").unwrap();
+    /// let (q, r) = total.div_rem(period);
+    /// assert_eq!(q, Decimal::<6>::from(3u64));
+    /// assert_eq!(r, Decimal::<6>::from_str("1.5").unwrap());
+    /// ```
+    pub fn div_rem(self, other: Self) -> (Self, Self) {
+        if other.0.is_zero() {
+            panic!("Division by zero");
+        }
+        self.checked_div_rem(other).expect("division result exceeds Uint128 range")
+    }
+
+    /// Whole-number quotient and remainder, or `None` on division by zero or if
+    /// the quotient exceeds `MAX`.
+    pub fn checked_div_rem(self, other: Self) -> Option<(Self, Self)> {
+        if other.0.is_zero() {
+            return None;
+        }
+        let quotient = self.0.u128() / other.0.u128();
+        let remainder = self.0.u128() - quotient * other.0.u128();
+        let quotient = quotient.checked_mul(Self::FRACTIONAL)?;
+        Some((Self::raw(quotient), Self::raw(remainder)))
+    }
+
+    /// Euclidean division: the whole-number quotient, so that
+    /// `div_euclid(other) * other + rem_euclid(other) == self`.
+    ///
+    /// For this unsigned type it is the quotient of [`Decimal::div_rem`]; see
+    /// [`SignedDecimal::div_euclid`] for the signed semantics.
+    ///
+    /// # Panics
+    ///
+    /// Panics on division by zero or if the quotient exceeds `MAX`.
+    pub fn div_euclid(self, other: Self) -> Self {
+        self.div_rem(other).0
+    }
+
+    /// Euclidean remainder. Identical to `%` for this unsigned type.
+    ///
+    /// # Panics
+    ///
+    /// Panics on division by zero.
+    pub fn rem_euclid(self, other: Self) -> Self {
+        self % other
+    }
+
+    /// Checked Euclidean division. Returns `None` on division by zero or overflow.
+    pub fn checked_div_euclid(self, other: Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(quotient, _)| quotient)
+    }
+
+    /// Checked Euclidean remainder. Returns `None` on division by zero.
+    pub fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+        self.checked_rem(other)
+    }
+
+    /// Checked power. Returns `None` on overflow.
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        // Special cases
+        if exp == 0 {
+            return Some(Self::ONE);
+        }
+        if exp == 1 {
+            return Some(self);
+        }
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        // Square-and-multiply over the bits of exp, least significant first
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut remaining = exp;
+        loop {
+            if remaining & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            remaining >>= 1;
+            if remaining == 0 {
+                return Some(result);
+            }
+            base = base.checked_mul(base)?;
+        }
+    }
+
+    // ========== Saturating Operations ==========
+
+    /// Saturating addition. Returns `MAX` on overflow.
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Saturating subtraction. Returns `ZERO` on underflow.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Saturating multiplication. Returns `MAX` on overflow.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        match self.checked_mul(other) {
+            Some(result) => result,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating addition of a whole number. Returns `MAX` on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// assert_eq!(Decimal6::MAX.saturating_add_uint(Uint128::new(1)), Decimal6::MAX);
+    /// ```
+    pub fn saturating_add_uint(self, rhs: Uint128) -> Self {
+        self.checked_add_uint(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Saturating subtraction of a whole number. Returns `ZERO` on underflow.
+    pub fn saturating_sub_uint(self, rhs: Uint128) -> Self {
+        self.checked_sub_uint(rhs).unwrap_or(Self::ZERO)
+    }
+
+    // ========== Wrapping Operations ==========
+    //
+    // These wrap modulo 2^128 atomics and never panic. The results are not
+    // financially meaningful; they exist for low-level uses such as hashing.
+
+    /// Wrapping addition modulo `2^128` atomics.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let d = Decimal::<6>::MAX.wrapping_add(Decimal::<6>::ONE);
+    /// assert_eq!(d, Decimal::<6>::raw(999_999));
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+
+    /// Wrapping subtraction modulo `2^128` atomics.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+
+    /// Wrapping multiplication: the truncated product `a * b / 10^D` reduced
+    /// modulo `2^128` atomics.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        // u128 * u128 always fits in Uint256, so only the final reduction wraps
+        let product = Uint256::from(self.0) * Uint256::from(other.0)
+            / Uint256::from(Self::FRACTIONAL);
+        let bytes = product.to_le_bytes();
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&bytes[..16]);
+        Self::raw(u128::from_le_bytes(low))
+    }
+
+    // ========== Rounding & Math ==========
+
+    /// Returns the largest integer less than or equal to this value.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let d = Decimal::<6>::from_str("1.7").unwrap();
+    /// assert_eq!(d.floor(), Decimal::<6>::from_str("1.0").unwrap());
+    /// ```
+    pub fn floor(self) -> Self {
+        Self(
+            (self.0.u128() / Self::FRACTIONAL * Self::FRACTIONAL).into(),
+        )
+    }
+
+    /// Returns the whole-number part as a `Uint128`, i.e. `atomics / FRACTIONAL`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// use std::str::FromStr;
+    /// let d = Decimal6::from_str("12.345").unwrap();
+    /// assert_eq!(d.integer_part(), Uint128::new(12));
+    /// assert_eq!(d.fractional_part(), Decimal6::from_str("0.345").unwrap());
+    /// ```
+    pub fn integer_part(self) -> Uint128 {
+        Uint128::new(self.0.u128() / Self::FRACTIONAL)
+    }
+
+    /// Returns the part below one, so that
+    /// `Decimal::from(self.integer_part()) + self.fractional_part() == self`.
+    pub fn fractional_part(self) -> Self {
+        Self::raw(self.0.u128() % Self::FRACTIONAL)
+    }
+
+    /// Returns the smallest integer greater than or equal to this value.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let d = Decimal::<6>::from_str("1.1").unwrap();
+    /// assert_eq!(d.ceil(), Decimal::<6>::from_str("2.0").unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value has a fractional part and lies above the largest
+    /// integer representable at this precision (i.e. within one unit of `MAX`).
+    /// Use [`Decimal::checked_ceil`] to handle it.
+    pub fn ceil(self) -> Self {
+        self.checked_ceil().expect("attempt to add with overflow")
+    }
+
+    /// Returns the largest integer less than or equal to this value.
+    ///
+    /// Flooring truncates and can never overflow, so this always returns `Some`;
+    /// it exists for symmetry with [`Decimal::checked_ceil`].
+    pub fn checked_floor(self) -> Option<Self> {
+        Some(self.floor())
+    }
+
+    /// Returns the smallest integer greater than or equal to this value,
+    /// or `None` if that integer exceeds `MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    /// let d = Decimal6::from_str("1.5").unwrap();
+    /// assert_eq!(d.checked_ceil(), Some(Decimal6::from_str("2.0").unwrap()));
+    /// assert_eq!(Decimal6::MAX.checked_ceil(), None);
+    /// ```
+    pub fn checked_ceil(self) -> Option<Self> {
+        let floor = self.floor();
+        if self == floor {
+            Some(floor)
+        } else {
+            floor.checked_add(Self::ONE)
+        }
+    }
+
+    /// Round to `places` decimal places (half-up), keeping precision `D`.
+    ///
+    /// Returns `self` unchanged when `places >= D`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rounding up exceeds `MAX`. Use [`Decimal::checked_round_to`] to handle it.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal18;
+    /// use std::str::FromStr;
+    /// let d = Decimal18::from_str("1.23456789").unwrap();
+    /// assert_eq!(d.round_to(4), Decimal18::from_str("1.2346").unwrap());
+    /// ```
+    pub fn round_to(self, places: u32) -> Self {
+        self.checked_round_to(places).expect("overflow in round_to")
+    }
+
+    /// Round to `places` decimal places (half-up), returning `None` if rounding
+    /// up exceeds `MAX`.
+    pub fn checked_round_to(self, places: u32) -> Option<Self> {
+        if places >= D {
+            return Some(self);
+        }
+
+        let scale = Uint256::from(pow10(D - places));
+        let rounded = div_rounded(Uint256::from(self.0), scale, RoundingMode::HalfUp) * scale;
+        Uint128::try_from(rounded).ok().map(Self)
+    }
+
+    /// Square root, truncated to `D` places.
+    ///
+    /// Computed as the integer square root of `atomics * 10^D` in `Uint256`, so
+    /// the result is accurate to all `D` places at any precision.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let d = Decimal::<6>::from_str("4.0").unwrap();
+    /// assert_eq!(d.sqrt(), Decimal::<6>::from_str("2.0").unwrap());
+    /// ```
+    pub fn sqrt(self) -> Self {
+        self.checked_sqrt().expect("sqrt overflow")
+    }
+
+    /// Square root, truncated to `D` places.
+    ///
+    /// `atomics * 10^D` is below `2^128 * 10^38`, which fits in `Uint256`, and its
+    /// root fits in `Uint128`, so this returns `Some` for every valid precision.
+    /// It exists for symmetry with the other checked operations.
+    pub fn checked_sqrt(self) -> Option<Self> {
+        let scaled = Uint256::from(self.0)
+            .checked_mul(Uint256::from(Self::FRACTIONAL))
+            .ok()?;
+        Uint128::try_from(scaled.isqrt()).ok().map(Self)
+    }
+
+    /// Power function.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let d = Decimal::<6>::from_str("2.0").unwrap();
+    /// assert_eq!(d.pow(3), Decimal::<6>::from_str("8.0").unwrap());
+    /// ```
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).expect("overflow in pow")
+    }
+
+    /// Saturating power. Returns `MAX` on overflow.
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap_or(Self::MAX)
+    }
+
+    /// Fallible power. Returns `Err(Overflow)` on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal};
+    /// let two = Decimal::<6>::from(2u64);
+    /// assert_eq!(two.try_pow(3), Ok(Decimal::<6>::from(8u64)));
+    /// assert_eq!(two.try_pow(200), Err(CustomDecimalError::Overflow));
+    /// ```
+    pub fn try_pow(self, exp: u32) -> Result<Self, CustomDecimalError> {
+        self.checked_pow(exp).ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Fallible addition. Returns `Err(Overflow)` on overflow.
+    pub fn try_add(self, other: Self) -> Result<Self, CustomDecimalError> {
+        self.checked_add(other).ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Fallible subtraction. Returns `Err(Underflow)` if `other > self`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6};
+    /// assert_eq!(Decimal6::ZERO.try_sub(Decimal6::ONE), Err(CustomDecimalError::Underflow));
+    /// ```
+    pub fn try_sub(self, other: Self) -> Result<Self, CustomDecimalError> {
+        self.checked_sub(other).ok_or(CustomDecimalError::Underflow)
+    }
+
+    /// Fallible multiplication. Returns `Err(Overflow)` on overflow.
+    pub fn try_mul(self, other: Self) -> Result<Self, CustomDecimalError> {
+        self.checked_mul(other).ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Fallible division. Returns `Err(DivisionByZero)` for a zero divisor and
+    /// `Err(Overflow)` if the quotient exceeds `MAX`.
+    pub fn try_div(self, other: Self) -> Result<Self, CustomDecimalError> {
+        if other.is_zero() {
+            return Err(CustomDecimalError::DivisionByZero);
+        }
+        self.checked_div(other).ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Compound growth `self * (ONE + rate)^periods`.
+    ///
+    /// The growth factor is computed with [`Decimal::checked_pow`], truncating
+    /// at each multiplication, and applied to `self` once at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow. Use [`Decimal::checked_compound`] to handle it.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// let principal = Decimal6::from(1000u64);
+    /// let total = principal.compound(Decimal6::percent(5), 3);
+    /// assert_eq!(total.to_string(), "1157.625");
+    /// ```
+    pub fn compound(self, rate: Self, periods: u32) -> Self {
+        self.checked_compound(rate, periods).expect("overflow in compound")
+    }
+
+    /// Compound growth `self * (ONE + rate)^periods`, returning `Overflow` if
+    /// the growth factor or the result exceeds `MAX`.
+    pub fn checked_compound(self, rate: Self, periods: u32) -> Result<Self, CustomDecimalError> {
+        Self::ONE
+            .checked_add(rate)
+            .and_then(|factor| factor.checked_pow(periods))
+            .and_then(|factor| self.checked_mul(factor))
+            .ok_or(CustomDecimalError::Overflow)
+    }
+
+    // ========== Comparisons ==========
+
+    /// Returns the minimum of two values.
+    ///
+    /// Same as `Ord::min`; kept inherent so it needs no trait import.
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Returns the maximum of two values.
+    ///
+    /// Same as `Ord::max`; kept inherent so it needs no trait import.
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the value to the range `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let fee = Decimal::<6>::percent(12);
+    /// let clamped = fee.clamp(Decimal::<6>::percent(1), Decimal::<6>::percent(10));
+    /// assert_eq!(clamped, Decimal::<6>::percent(10));
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "clamp requires min <= max");
+        self.max(min).min(max)
+    }
+
+    /// Returns the midpoint of `self` and `other` without overflowing.
+    ///
+    /// When the atomic sum is odd the result rounds down to the lower atomic unit.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let mid = Decimal::<6>::from(2u64).midpoint(Decimal::<6>::from(4u64));
+    /// assert_eq!(mid, Decimal::<6>::from(3u64));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        let (a, b) = (self.0.u128(), other.0.u128());
+        Self::raw(a / 2 + b / 2 + (a & b & 1))
+    }
+
+    /// Linear interpolation `self * (1 - t) + other * t`, computed in `Uint256`
+    /// with a single truncation, so it works for either ordering of the endpoints.
+    ///
+    /// `t` is clamped to `[0, 1]`, so the result always lies between `self` and
+    /// `other` and cannot overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let t = Decimal::<6>::from_str("0.25").unwrap();
+    /// let ten = Decimal::<6>::from(10u64);
+    /// assert_eq!(Decimal::<6>::ZERO.lerp(ten, t), Decimal::<6>::from_str("2.5").unwrap());
+    /// assert_eq!(ten.lerp(Decimal::<6>::ZERO, t), Decimal::<6>::from_str("7.5").unwrap());
+    /// ```
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        let t = Uint256::from(t.min(Self::ONE).0);
+        let one = Uint256::from(Self::FRACTIONAL);
+        let weighted = Uint256::from(self.0) * (one - t) + Uint256::from(other.0) * t;
+        // A convex combination of two Uint128 values fits in Uint128
+        Self(Uint128::try_from(weighted / one).expect("lerp result exceeds Uint128 range"))
+    }
+
+    /// Weighted mean `sum(v_i * w_i) / sum(w_i)`, accumulated in `Uint256` and
+    /// divided once at the end (truncating).
+    ///
+    /// Returns `None` if the slices differ in length, the total weight is zero, or
+    /// the accumulated products overflow `Uint256` (e.g. several `MAX` values with
+    /// weights near `Uint128::MAX`).
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let prices = [Decimal::<6>::from(1u64), Decimal::<6>::from(4u64)];
+    /// let weights = [Uint128::new(2), Uint128::new(1)];
+    /// assert_eq!(Decimal::weighted_average(&prices, &weights), Some(Decimal::<6>::from(2u64)));
+    /// ```
+    pub fn weighted_average(values: &[Self], weights: &[Uint128]) -> Option<Self> {
+        if values.len() != weights.len() {
+            return None;
+        }
+
+        let mut weighted_sum = Uint256::zero();
+        let mut total_weight = Uint256::zero();
+        for (value, weight) in values.iter().zip(weights) {
+            let term = Uint256::from(value.0) * Uint256::from(*weight);
+            weighted_sum = weighted_sum.checked_add(term).ok()?;
+            total_weight += Uint256::from(*weight);
+        }
+        if total_weight.is_zero() {
+            return None;
+        }
+
+        // The mean never exceeds the largest value, so it fits in Uint128
+        Uint128::try_from(weighted_sum / total_weight).ok().map(Self)
+    }
+
+    /// Sum an iterator with `checked_add`, returning `Overflow` instead of
+    /// panicking like [`Sum`].
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6};
+    /// let total = Decimal6::try_sum([Decimal6::ONE, Decimal6::ONE].into_iter());
+    /// assert_eq!(total, Ok(Decimal6::from(2u64)));
+    /// let overflow = Decimal6::try_sum([Decimal6::MAX, Decimal6::ONE].into_iter());
+    /// assert_eq!(overflow, Err(CustomDecimalError::Overflow));
+    /// ```
+    pub fn try_sum<I: Iterator<Item = Self>>(mut iter: I) -> Result<Self, CustomDecimalError> {
+        iter.try_fold(Self::ZERO, |acc, x| acc.checked_add(x))
+            .ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Multiply an iterator with `checked_mul`, returning `Overflow` instead of
+    /// panicking like [`Product`].
+    pub fn try_product<I: Iterator<Item = Self>>(mut iter: I) -> Result<Self, CustomDecimalError> {
+        iter.try_fold(Self::ONE, |acc, x| acc.checked_mul(x))
+            .ok_or(CustomDecimalError::Overflow)
+    }
+
+    /// Returns the absolute difference between two values.
+    ///
+    /// Always subtracts the smaller value from the larger, so it never overflows.
+    pub fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// Saturating absolute difference. Identical to `abs_diff`, which cannot
+    /// overflow; provided for naming consistency in generic code.
+    pub fn saturating_abs_diff(self, other: Self) -> Self {
+        self.abs_diff(other)
+    }
+
+    /// Returns the absolute difference between two values, capped at `cap`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// let a = Decimal::<6>::percent(150);
+    /// let b = Decimal::<6>::percent(100);
+    /// assert_eq!(a.abs_diff_capped(b, Decimal::<6>::percent(10)), Decimal::<6>::percent(10));
+    /// ```
+    pub fn abs_diff_capped(self, other: Self, cap: Self) -> Self {
+        self.abs_diff(other).min(cap)
+    }
+
+    /// Returns true when the values differ by at most `tolerance`.
+    ///
+    /// Useful for comparing results of lossy division, which often differ by
+    /// a single atomic unit.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    ///
+    /// let third = Decimal::<6>::ONE / Decimal::<6>::from(3u64);
+    /// let almost_one = third * Decimal::<6>::from(3u64); // 0.999999
+    /// assert!(almost_one.approx_eq(Decimal::<6>::ONE, Decimal::<6>::raw(1)));
+    /// ```
+    pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+        self.abs_diff(other) <= tolerance
+    }
+
+    /// Returns true when the values differ by at most `ulps` atomic units
+    /// (each `10^-D`).
+    pub fn approx_eq_ulps(self, other: Self, ulps: u128) -> bool {
+        self.approx_eq(other, Self::raw(ulps))
+    }
+
+    /// Returns true when `self` and a value of another precision agree to the
+    /// coarser of the two precisions; the finer value is truncated first.
+    ///
+    /// This is looser than `value_cmp(..) == Ordering::Equal`, which compares
+    /// exact values.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal6, Decimal9};
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    ///
+    /// let fine = Decimal9::from_str("1.123456789").unwrap();
+    /// let coarse = Decimal6::from_str("1.123456").unwrap();
+    /// assert!(fine.value_eq(&coarse));
+    /// assert_eq!(fine.value_cmp(&coarse), Ordering::Greater);
+    /// ```
+    pub fn value_eq<const D2: u32>(&self, other: &Decimal<D2>) -> bool {
+        if D <= D2 {
+            self.0.u128() == other.0.u128() / pow10(D2 - D)
+        } else {
+            self.0.u128() / pow10(D - D2) == other.0.u128()
+        }
+    }
+
+    /// Compares exact values across precisions, scaling the lower-precision
+    /// side up in `Uint256` so nothing is truncated.
+    pub fn value_cmp<const D2: u32>(&self, other: &Decimal<D2>) -> Ordering {
+        let ours = Uint256::from(self.0);
+        let theirs = Uint256::from(other.0);
+        if D <= D2 {
+            (ours * Uint256::from(pow10(D2 - D))).cmp(&theirs)
+        } else {
+            ours.cmp(&(theirs * Uint256::from(pow10(D - D2))))
+        }
+    }
+
+    /// Returns the unit in the last place, [`Self::EPSILON`]. Fixed-point
+    /// spacing is uniform, so this is the same for every value.
+    pub const fn ulp(self) -> Self {
+        Self::EPSILON
+    }
+
+    // ========== Conversions to Uint128 ==========
+
+    /// Convert to `Uint128` by flooring.
+    pub fn to_uint_floor(self) -> Uint128 {
+        self.0 / Uint128::from(Self::FRACTIONAL)
+    }
+
+    /// Convert to `Uint128` by ceiling.
+    pub fn to_uint_ceil(self) -> Uint128 {
+        self.ceil().to_uint_floor()
+    }
+
+    /// Convert to `Uint128` by rounding to the nearest integer, ties away
+    /// from zero (`2.5 -> 3`).
+    pub fn to_uint_round(self) -> Uint128 {
+        self.to_uint_rounded(RoundingMode::HalfUp)
+    }
+
+    /// Convert to `Uint128` by rounding to the nearest integer, ties to even
+    /// (`2.5 -> 2`, `3.5 -> 4`).
+    pub fn to_uint_round_bankers(self) -> Uint128 {
+        self.to_uint_rounded(RoundingMode::HalfEven)
+    }
+
+    fn to_uint_rounded(self, mode: RoundingMode) -> Uint128 {
+        let rounded = div_rounded(Uint256::from(self.0), Uint256::from(Self::FRACTIONAL), mode);
+        // Dividing by 10^D leaves room to round up, except for D = 0 where
+        // nothing is rounded
+        Uint128::try_from(rounded).unwrap()
+    }
+
+    /// Convert to `Uint128` by flooring, capped at `max`.
+    pub fn to_uint_floor_clamped(self, max: Uint128) -> Uint128 {
+        self.to_uint_floor().min(max)
+    }
+
+    /// Multiply by `Uint128` and floor the result.
+    ///
+    /// Computes `self * rhs` from the full `Uint256` atomic product.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let price = Decimal::<6>::from_ratio(5u128, 2u128); // 2.5
+    /// assert_eq!(price.mul_floor(Uint128::new(3)), Uint128::new(7));
+    /// ```
+    pub fn mul_floor(self, rhs: Uint128) -> Uint128 {
+        self.mul_uint_rounded(rhs, RoundingMode::Floor)
+    }
+
+    /// Multiply by `Uint128` and ceil the result.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let price = Decimal::<6>::from_ratio(5u128, 2u128); // 2.5
+    /// assert_eq!(price.mul_ceil(Uint128::new(3)), Uint128::new(8));
+    /// ```
+    pub fn mul_ceil(self, rhs: Uint128) -> Uint128 {
+        self.mul_uint_rounded(rhs, RoundingMode::Ceil)
+    }
+
+    /// Divide by `Uint128` and floor the result to an integer.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let total = Decimal::<6>::from_ratio(15u128, 2u128); // 7.5
+    /// assert_eq!(total.div_floor(Uint128::new(2)), Uint128::new(3));
+    /// ```
+    pub fn div_floor(self, rhs: Uint128) -> Uint128 {
+        self.div_uint_rounded(rhs, RoundingMode::Floor)
+    }
+
+    /// Divide by `Uint128` and ceil the result to an integer.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal;
+    /// use cosmwasm_std::Uint128;
+    /// let total = Decimal::<6>::from_ratio(15u128, 2u128); // 7.5
+    /// assert_eq!(total.div_ceil(Uint128::new(2)), Uint128::new(4));
+    /// ```
+    pub fn div_ceil(self, rhs: Uint128) -> Uint128 {
+        self.div_uint_rounded(rhs, RoundingMode::Ceil)
+    }
+
+    fn mul_uint_rounded(self, rhs: Uint128, mode: RoundingMode) -> Uint128 {
+        self.checked_mul_uint_rounded(rhs, mode)
+            .expect("multiplication result exceeds Uint128 range")
+    }
+
+    fn checked_mul_uint_rounded(self, rhs: Uint128, mode: RoundingMode) -> Option<Uint128> {
+        // Cannot overflow: (2^128 - 1)^2 < 2^256
+        let product = Uint256::from(self.0) * Uint256::from(rhs);
+        let result = div_rounded(product, Uint256::from(Self::FRACTIONAL), mode);
+
+        Uint128::try_from(result).ok()
+    }
+
+    fn div_uint_rounded(self, rhs: Uint128, mode: RoundingMode) -> Uint128 {
+        if rhs.is_zero() {
+            panic!("Division by zero");
+        }
+
+        let divisor = Uint256::from(rhs) * Uint256::from(Self::FRACTIONAL);
+        let result = div_rounded(Uint256::from(self.0), divisor, mode);
+
+        // The quotient never exceeds the dividend's atomics
+        Uint128::try_from(result).unwrap()
+    }
+
+    /// Multiply by `Uint128` and floor the result.
+    ///
+    /// Equivalent to [`Decimal::mul_floor`].
+    pub fn mul_uint_floor(self, rhs: Uint128) -> Uint128 {
+        self.mul_uint_rounded(rhs, RoundingMode::Floor)
+    }
+
+    /// Multiply by `Uint128` and ceil the result.
+    ///
+    /// Rounds up whenever the `Uint256` atomic product leaves a nonzero remainder
+    /// modulo `FRACTIONAL`. Equivalent to [`Decimal::mul_ceil`].
+    pub fn mul_uint_ceil(self, rhs: Uint128) -> Uint128 {
+        self.mul_uint_rounded(rhs, RoundingMode::Ceil)
+    }
+
+    /// Multiply by `Uint128`, truncating like `self * rhs`. Returns `None`
+    /// if the result exceeds `Uint128::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// let rate = Decimal6::percent(150);
+    /// assert_eq!(rate.checked_mul_uint(Uint128::new(3)), Some(Uint128::new(4)));
+    /// assert_eq!(Decimal6::MAX.checked_mul_uint(Uint128::MAX), None);
+    /// ```
+    pub fn checked_mul_uint(self, rhs: Uint128) -> Option<Uint128> {
+        self.checked_mul_uint_floor(rhs)
+    }
+
+    /// Multiply by `Uint128` and floor the result. Returns `None` if the
+    /// result exceeds `Uint128::MAX`.
+    pub fn checked_mul_uint_floor(self, rhs: Uint128) -> Option<Uint128> {
+        self.checked_mul_uint_rounded(rhs, RoundingMode::Floor)
+    }
+
+    /// Multiply by `Uint128` and ceil the result. Returns `None` if the
+    /// result exceeds `Uint128::MAX`.
+    pub fn checked_mul_uint_ceil(self, rhs: Uint128) -> Option<Uint128> {
+        self.checked_mul_uint_rounded(rhs, RoundingMode::Ceil)
+    }
+
+    /// Dot product `sum(prices[i] * quantities[i])`, truncated once at the end.
+    ///
+    /// The atomic products are summed in `Uint256` before dividing by
+    /// `FRACTIONAL`, so the result can exceed the sum of per-element
+    /// `price * qty`, which floors every term. Returns `None` if the slices
+    /// differ in length or the result exceeds `Uint128::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use cosmwasm_std::Uint128;
+    /// let prices = [Decimal6::percent(50), Decimal6::percent(50)];
+    /// let quantities = [Uint128::new(3), Uint128::new(3)];
+    /// // Flooring each term would give 1 + 1
+    /// assert_eq!(Decimal6::dot(&prices, &quantities), Some(Uint128::new(3)));
+    /// ```
+    pub fn dot(prices: &[Self], quantities: &[Uint128]) -> Option<Uint128> {
+        if prices.len() != quantities.len() {
+            return None;
+        }
+        let products = prices
+            .iter()
+            .zip(quantities)
+            .map(|(price, qty)| Uint256::from(price.0) * Uint256::from(*qty));
+        let total = Self::sum_wide(products)?;
+        Uint128::try_from(total / Uint256::from(Self::FRACTIONAL)).ok()
+    }
+
+    /// Dot product of two decimal slices, truncated once at the end.
+    ///
+    /// See [`Decimal::dot`]. Returns `None` if the slices differ in length or
+    /// the result exceeds `MAX`.
+    pub fn dot_decimal(values: &[Self], weights: &[Self]) -> Option<Self> {
+        if values.len() != weights.len() {
+            return None;
+        }
+        let products = values
+            .iter()
+            .zip(weights)
+            .map(|(value, weight)| Uint256::from(value.0) * Uint256::from(weight.0));
+        let total = Self::sum_wide(products)?;
+        Uint128::try_from(total / Uint256::from(Self::FRACTIONAL))
+            .ok()
+            .map(Self)
+    }
+
+    fn sum_wide(mut products: impl Iterator<Item = Uint256>) -> Option<Uint256> {
+        products.try_fold(Uint256::zero(), |acc, product| acc.checked_add(product).ok())
+    }
+
+    // ========== Float Conversions ==========
+
+    /// Convert to `f64` for off-chain analytics and logging.
+    ///
+    /// This is lossy: `f64` holds about 15-17 significant digits, so large
+    /// magnitudes or long fractional parts are rounded. Never use the result
+    /// for on-chain math.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    /// assert_eq!(Decimal6::from_str("1.5").unwrap().to_f64(), 1.5);
+    /// ```
+    pub fn to_f64(self) -> f64 {
+        self.0.u128() as f64 / Self::FRACTIONAL as f64
+    }
+
+    /// Convert from `f64`, rounding to the nearest value with `D` decimals.
+    ///
+    /// Returns `ConversionError` for NaN, infinities and negative values, and
+    /// `Overflow` if the value exceeds `MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{CustomDecimalError, Decimal6};
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal6::try_from_f64(0.1), Ok(Decimal6::from_str("0.1").unwrap()));
+    /// assert!(matches!(
+    ///     Decimal6::try_from_f64(f64::NAN),
+    ///     Err(CustomDecimalError::ConversionError(_))
+    /// ));
+    /// ```
+    pub fn try_from_f64(value: f64) -> Result<Self, CustomDecimalError> {
+        if value.is_nan() {
+            return Err(CustomDecimalError::ConversionError("f64 value is NaN".to_string()));
+        }
+        if value.is_infinite() {
+            return Err(CustomDecimalError::ConversionError("f64 value is infinite".to_string()));
+        }
+        if value < 0.0 {
+            return Err(CustomDecimalError::ConversionError(format!(
+                "f64 value {} is negative",
+                value
+            )));
+        }
+
+        let scaled = (value * Self::FRACTIONAL as f64).round();
+        // u128::MAX rounds up to 2^128 as f64, so anything at or above it overflows
+        if scaled >= u128::MAX as f64 {
+            return Err(CustomDecimalError::Overflow);
+        }
+        Ok(Self(Uint128::new(scaled as u128)))
+    }
+}
+
+// ========== Type Conversions ==========
+
+/// From Uint128 (treats as integer, e.g., 5 -> 5.0)
+impl<const D: u32> From<Uint128> for Decimal<D> {
+    fn from(value: Uint128) -> Self {
+        Self(value * Uint128::from(Self::FRACTIONAL))
+    }
+}
+
+/// From u128 (treats as integer)
+impl<const D: u32> From<u128> for Decimal<D> {
+    fn from(value: u128) -> Self {
+        Self::from(Uint128::from(value))
+    }
+}
+
+/// From u64 (treats as integer)
+impl<const D: u32> From<u64> for Decimal<D> {
+    fn from(value: u64) -> Self {
+        Self::from(Uint128::from(value))
+    }
+}
+
+/// From Uint64 (treats as integer)
+impl<const D: u32> From<Uint64> for Decimal<D> {
+    fn from(value: Uint64) -> Self {
+        Self::from(Uint128::from(value))
+    }
+}
+
+macro_rules! impl_from_small_uint {
+    ($($t:ty),*) => {
+        $(
+            /// Treats the value as an integer
+            impl<const D: u32> From<$t> for Decimal<D> {
+                fn from(value: $t) -> Self {
+                    Self::from(Uint128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_uint!(u8, u16, u32);
+
+/// From i64 (treats as integer), returning `ConversionError` for negative values
+/// and `Overflow` when the scaled value exceeds `MAX`
+impl<const D: u32> TryFrom<i64> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::try_from(i128::from(value))
+    }
+}
+
+/// From i128 (treats as integer), returning `ConversionError` for negative values
+/// and `Overflow` when the scaled value exceeds `MAX`
+impl<const D: u32> TryFrom<i128> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: i128) -> Result<Self, Self::Error> {
+        let integer = u128::try_from(value).map_err(|_| {
+            CustomDecimalError::ConversionError(format!("{} is negative", value))
+        })?;
+        Uint128::new(integer)
+            .checked_mul(Uint128::new(Self::FRACTIONAL))
+            .map(Self)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+}
+
+/// Convert a whole-number decimal to Uint128, returning `ConversionError` if it
+/// has a fractional part. Use `to_uint_floor`/`to_uint_ceil` to round instead.
+impl<const D: u32> TryFrom<Decimal<D>> for Uint128 {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: Decimal<D>) -> Result<Self, Self::Error> {
+        if value.is_integer() {
+            Ok(value.to_uint_floor())
+        } else {
+            Err(CustomDecimalError::ConversionError(format!(
+                "{} is not an integer",
+                value
+            )))
+        }
+    }
+}
+
+/// Convert from cosmwasm_std::Decimal (truncates precision from 18 to D decimals)
+///
+/// # Panics
+///
+/// For `D > 18` the atomics are scaled up, which panics if the result exceeds
+/// `Uint128`. Use [`Decimal::try_from_std_decimal`] to handle that case without panicking.
+impl<const D: u32> From<StdDecimal> for Decimal<D> {
+    fn from(decimal: StdDecimal) -> Self {
+        Self::try_from_std_decimal(decimal).expect("StdDecimal conversion overflow")
+    }
+}
+
+/// Convert to cosmwasm_std::Decimal (scales from D to 18 decimals)
+///
+/// # Panics
+///
+/// For `D < 18` the atomics are scaled up, which panics if the result exceeds
+/// `Uint128`. Use [`Decimal::try_to_std_decimal`] to handle that case.
+impl<const D: u32> From<Decimal<D>> for StdDecimal {
+    fn from(custom: Decimal<D>) -> Self {
+        custom.try_to_std_decimal().expect("StdDecimal conversion overflow")
+    }
+}
+
+/// Convert to Decimal256
+impl<const D: u32> From<Decimal<D>> for Decimal256 {
+    fn from(custom: Decimal<D>) -> Self {
+        // First convert to StdDecimal, then to Decimal256
+        let decimal: StdDecimal = custom.into();
+        decimal.into()
+    }
+}
+
+/// Try to convert from Decimal256
+impl<const D: u32> TryFrom<Decimal256> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: Decimal256) -> Result<Self, Self::Error> {
+        // Try to convert Decimal256 -> StdDecimal first
+        let decimal =
+            StdDecimal::try_from(value).map_err(|_| CustomDecimalError::ConversionError(
+                "Decimal256 value too large for Decimal".to_string(),
+            ))?;
+        Ok(decimal.into())
+    }
+}
+
+// ========== Comparison with StdDecimal ==========
+
+impl<const D: u32> Decimal<D> {
+    /// Compare against a `StdDecimal` by value, scaling the lower-precision side
+    /// up to the other's precision in `Uint256` so the result is exact.
+    fn cmp_std(&self, other: &StdDecimal) -> Ordering {
+        let ours = Uint256::from(self.0);
+        let theirs = Uint256::from(other.atomics());
+        if D <= 18 {
+            (ours * Uint256::from(pow10(18 - D))).cmp(&theirs)
+        } else {
+            ours.cmp(&(theirs * Uint256::from(pow10(D - 18))))
+        }
+    }
+}
+
+/// Compares by value, so `Decimal6` 1.5 equals `StdDecimal` 1.5 despite
+/// different atomics.
+impl<const D: u32> PartialEq<StdDecimal> for Decimal<D> {
+    fn eq(&self, other: &StdDecimal) -> bool {
+        self.cmp_std(other) == Ordering::Equal
+    }
+}
+
+impl<const D: u32> PartialEq<Decimal<D>> for StdDecimal {
+    fn eq(&self, other: &Decimal<D>) -> bool {
+        other == self
+    }
+}
+
+impl<const D: u32> PartialOrd<StdDecimal> for Decimal<D> {
+    fn partial_cmp(&self, other: &StdDecimal) -> Option<Ordering> {
+        Some(self.cmp_std(other))
+    }
+}
+
+impl<const D: u32> PartialOrd<Decimal<D>> for StdDecimal {
+    fn partial_cmp(&self, other: &Decimal<D>) -> Option<Ordering> {
+        Some(other.cmp_std(self).reverse())
+    }
+}
+
+// ========== Comparison with Uint128 ==========
+
+impl<const D: u32> Decimal<D> {
+    /// Compare against a whole number, scaling it by `FRACTIONAL` in `Uint256`
+    /// so integers above `MAX` compare correctly.
+    fn cmp_uint(&self, other: &Uint128) -> Ordering {
+        Uint256::from(self.0).cmp(&other.full_mul(Uint128::new(Self::FRACTIONAL)))
+    }
+}
+
+/// Compares by value against a whole number: `Decimal6` 100.0 equals
+/// `Uint128` 100, and 100.5 is greater.
+impl<const D: u32> PartialEq<Uint128> for Decimal<D> {
+    fn eq(&self, other: &Uint128) -> bool {
+        self.cmp_uint(other) == Ordering::Equal
+    }
+}
+
+impl<const D: u32> PartialEq<Decimal<D>> for Uint128 {
+    fn eq(&self, other: &Decimal<D>) -> bool {
+        other == self
+    }
+}
+
+impl<const D: u32> PartialOrd<Uint128> for Decimal<D> {
+    fn partial_cmp(&self, other: &Uint128) -> Option<Ordering> {
+        Some(self.cmp_uint(other))
+    }
+}
+
+impl<const D: u32> PartialOrd<Decimal<D>> for Uint128 {
+    fn partial_cmp(&self, other: &Decimal<D>) -> Option<Ordering> {
+        Some(other.cmp_uint(self).reverse())
+    }
+}
+
+// ========== Display & FromStr ==========
+
+/// Trailing fractional zeros are trimmed unless a precision is given
+/// (`{:.2}`), in which case exactly that many digits are printed, rounded
+/// half-up. Width, fill, alignment and `+` flags behave as for integers.
+impl<const D: u32> fmt::Display for Decimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = format::display_digits(*self, f.precision());
+        format::pad_digits(f, true, &digits, digits.len(), format::excess_zeros(f.precision()))
+    }
+}
+
+/// Scientific notation with a mantissa in `[1, 10)`, e.g. `1.5e0` or `1e-6`.
+///
+/// The exponent is computed exactly from the atomics. A precision (`{:.2e}`)
+/// fixes the mantissa digits, rounded half-up.
+impl<const D: u32> fmt::LowerExp for Decimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = format::exp_digits(*self, f.precision(), false);
+        let marker = digits.rfind('e').unwrap();
+        format::pad_digits(f, true, &digits, marker, format::excess_zeros(f.precision()))
+    }
+}
+
+/// Like [`fmt::LowerExp`] with an upper-case `E`.
+impl<const D: u32> fmt::UpperExp for Decimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = format::exp_digits(*self, f.precision(), true);
+        let marker = digits.rfind('E').unwrap();
+        format::pad_digits(f, true, &digits, marker, format::excess_zeros(f.precision()))
+    }
+}
+
+impl<const D: u32> fmt::Debug for Decimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Decimal<{}>({})", D, self)
+    }
+}
+
+/// Strip a single leading `+` and any `_` digit separators from a number.
+///
+/// Each underscore must sit between two digits, so `"1_000"` is accepted while
+/// `"1__0"`, `"_1"` and `"1_.5"` are not. Input without either is returned as is.
+pub(crate) fn strip_separators(s: &str) -> Result<Cow<'_, str>, CustomDecimalError> {
+    let body = match s.strip_prefix('+') {
+        Some(body) if body.starts_with(['+', '-']) => {
+            return Err(CustomDecimalError::ParseError(format!("Invalid sign: {}", s)));
+        }
+        Some(body) => body,
+        None => s,
+    };
+    if !body.contains('_') {
+        return Ok(Cow::Borrowed(body));
+    }
+
+    let bytes = body.as_bytes();
+    let is_digit_at = |i: Option<usize>| i.and_then(|i| bytes.get(i)).is_some_and(u8::is_ascii_digit);
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'_' && !(is_digit_at(i.checked_sub(1)) && is_digit_at(Some(i + 1))) {
+            return Err(CustomDecimalError::ParseError(format!(
+                "Invalid digit separator: {}",
+                s
+            )));
+        }
+    }
+    Ok(Cow::Owned(body.replace('_', "")))
+}
+
+impl<const D: u32> FromStr for Decimal<D> {
+    type Err = CustomDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = &*strip_separators(s)?;
+        if s.is_empty() {
+            return Err(CustomDecimalError::ParseError("empty string".to_string()));
+        }
+        if s.starts_with('-') {
+            return Err(CustomDecimalError::ParseError(
+                "negative values are not supported; use SignedDecimal".to_string(),
+            ));
+        }
+        if s == "." {
+            return Err(CustomDecimalError::ParseError(format!("Invalid decimal format: {}", s)));
+        }
+
+        // Scientific notation, e.g. "1.5e-6" or "2.5E3"
+        if let Some((mantissa, exponent)) = s.split_once(['e', 'E']) {
+            let exp = exponent.parse::<i32>().map_err(|_| {
+                CustomDecimalError::ParseError(format!("Invalid exponent: {}", exponent))
+            })?;
+            let (integer, fractional) = match mantissa.split_once('.') {
+                Some((integer, fractional)) => (integer, Some(fractional)),
+                None => (mantissa, None),
+            };
+            return Self::from_parts_with_exponent(integer, fractional, exp);
+        }
+
+        let parts: Vec<&str> = s.split('.').collect();
+        // `u128::from_str` accepts a leading '+', which is only valid before the whole number
+        let parse_digits = |part: &str| match part.bytes().all(|b| b.is_ascii_digit()) {
+            true => part.parse::<u128>().ok(),
+            false => None,
+        };
+
+        match parts.len() {
+            1 => {
+                // Integer only
+                let integer = parse_digits(parts[0])
+                    .ok_or_else(|| CustomDecimalError::ParseError(format!("Invalid integer: {}", parts[0])))?;
+
+                let total = integer
+                    .checked_mul(Self::FRACTIONAL)
+                    .ok_or(CustomDecimalError::Overflow)?;
+
+                Ok(Self(Uint128::from(total)))
+            }
+            2 => {
+                // Integer and fractional parts; either may be empty (".5", "1.")
+                let integer = match parts[0] {
+                    "" => 0,
+                    part => parse_digits(part)
+                        .ok_or_else(|| CustomDecimalError::ParseError(format!("Invalid integer: {}", part)))?,
+                };
+
+                let fractional_str = parts[1];
+                if fractional_str.len() > D as usize {
+                    return Err(CustomDecimalError::ParseError(format!(
+                        "Too many decimal places: {} (max {})",
+                        fractional_str.len(),
+                        D
+                    )));
+                }
+
+                let fractional = match fractional_str {
+                    "" => 0,
+                    part => parse_digits(part)
+                        .ok_or_else(|| CustomDecimalError::ParseError(format!("Invalid fractional: {}", part)))?,
+                };
+
+                // Scale to D decimals
+                let scaled_fractional =
+                    fractional * pow10(D - fractional_str.len() as u32);
+
+                let total = integer
+                    .checked_mul(Self::FRACTIONAL)
+                    .and_then(|i| i.checked_add(scaled_fractional))
+                    .ok_or(CustomDecimalError::Overflow)?;
+
+                Ok(Self(Uint128::from(total)))
+            }
+            _ => Err(CustomDecimalError::ParseError(format!(
+                "Invalid decimal format: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Parse via `FromStr`, so strings work in generic `TryInto` code
+impl<const D: u32> TryFrom<&str> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parse via `FromStr`
+impl<const D: u32> TryFrom<String> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+// ========== Hash ==========
+
+/// Hashes the raw atomics, so values that compare equal hash equally
+/// (`"1.5"` and `"1.500000"` parse to the same atomics).
+impl<const D: u32> Hash for Decimal<D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.u128().hash(state);
+    }
+}
+
+// ========== Schema ==========
+
+/// A `cosmwasm_std::Decimal` string with up to 18 fractional digits, as
+/// `Serialize` writes and `Deserialize` reads for every `D`. Named per precision
+/// (`Decimal6`, `Decimal18`, ...) so definitions of different `D` do not
+/// collide. Written by hand because the derive macro refers to `std` paths.
+impl<const D: u32> JsonSchema for Decimal<D> {
+    fn schema_name() -> String {
+        format!("Decimal{}", D)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Owned(format!("cosmwasm_custom_decimal::Decimal<{}>", D))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("decimal".to_string()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(format!(
+                    "A fixed-point decimal with {} decimal places, serialized as a \
+                     string compatible with cosmwasm_std::Decimal (e.g. \"1.5\")",
+                    D
+                )),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^[0-9]+(\\.[0-9]{1,18})?$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+// ========== Fraction ==========
+
+/// A `Decimal<D>` is the fraction `atomics / 10^D`, so it can be passed to code
+/// written against `Fraction<Uint128>`.
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::Decimal6;
+/// use cosmwasm_std::{Fraction, Uint128};
+///
+/// fn apply<F: Fraction<Uint128>>(amount: Uint128, ratio: F) -> Uint128 {
+///     amount.multiply_ratio(ratio.numerator(), ratio.denominator())
+/// }
+///
+/// assert_eq!(apply(Uint128::new(200), Decimal6::percent(25)), Uint128::new(50));
+/// ```
+impl<const D: u32> Fraction<Uint128> for Decimal<D> {
+    fn numerator(&self) -> Uint128 {
+        Decimal::numerator(self)
+    }
+
+    fn denominator(&self) -> Uint128 {
+        Decimal::denominator(self)
+    }
+
+    /// Returns `1 / self`, truncated to `D` places, or `None` for zero or if
+    /// the reciprocal exceeds `MAX` (possible only for `D > 19`).
+    fn inv(&self) -> Option<Self> {
+        self.checked_inv()
+    }
+}
+
+// ========== Iterator Traits ==========
+
+impl<const D: u32> Sum for Decimal<D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, const D: u32> Sum<&'a Decimal<D>> for Decimal<D> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<const D: u32> Product for Decimal<D> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, const D: u32> Product<&'a Decimal<D>> for Decimal<D> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Decimal::<6>::ZERO.0, Uint128::zero());
+        assert_eq!(Decimal::<6>::ONE.0, Uint128::new(1_000_000));
+        assert_eq!(Decimal::<6>::DECIMAL_PLACES, 6);
+        assert_eq!(Decimal::<6>::FRACTIONAL, 1_000_000);
+
+        assert_eq!(Decimal::<9>::ONE.0, Uint128::new(1_000_000_000));
+        assert_eq!(Decimal::<9>::DECIMAL_PLACES, 9);
+
+        assert_eq!(Decimal::<18>::ONE.0, Uint128::new(1_000_000_000_000_000_000));
+        assert_eq!(Decimal::<18>::DECIMAL_PLACES, 18);
+    }
+
+    #[test]
+    fn test_epsilon() {
+        assert_eq!(Decimal6::EPSILON.to_string(), "0.000001");
+        assert_eq!(Decimal18::EPSILON, Decimal18::from_str("0.000000000000000001").unwrap());
+        assert_eq!(Decimal18::EPSILON * Decimal18::from(10u64.pow(18)), Decimal18::ONE);
+
+        assert_eq!(Decimal6::MAX.ulp(), Decimal6::EPSILON);
+        assert_eq!(Decimal6::ZERO.ulp(), Decimal6::EPSILON);
+        assert!(Decimal6::ONE.approx_eq(Decimal6::ONE - Decimal6::EPSILON, Decimal6::ONE.ulp()));
+    }
+
+    #[test]
+    fn test_raw() {
+        let d = Decimal::<6>::raw(1_500_000);
+        assert_eq!(d.0, Uint128::new(1_500_000));
+    }
+
+    #[test]
+    fn test_new() {
+        let d = Decimal6::new(Uint128::new(1_500_000));
+        assert_eq!(d, Decimal6::raw(1_500_000));
+        assert_eq!(d, Decimal6::from_str("1.5").unwrap());
+    }
+
+    #[test]
+    fn test_atomics_uint128() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        assert_eq!(d.atomics_uint128(), Uint128::new(d.atomics()));
+        assert_eq!(Decimal6::MAX.atomics_uint128(), Uint128::MAX);
+    }
+
+    #[test]
+    fn test_from_atomics() {
+        let d = Decimal::<6>::from_atomics(15u128, 1).unwrap();
+        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+
+        let d = Decimal::<6>::from_atomics(1_500_000u128, 6).unwrap();
+        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+
+        let d = Decimal::<6>::from_atomics(1_500_000_000u128, 9).unwrap();
+        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+    }
+
+    #[test]
+    fn test_checked_from_atomics() {
+        assert_eq!(Decimal6::checked_from_atomics(15u128, 1), Ok(Decimal6::raw(1_500_000)));
+        assert_eq!(Decimal6::checked_from_atomics(u128::MAX, 5), Err(CustomDecimalError::Overflow));
+
+        // Scale-down truncates and never fails, however many places are given
+        assert_eq!(Decimal6::checked_from_atomics(1_999_999u128, 7), Ok(Decimal6::raw(199_999)));
+        assert_eq!(Decimal6::checked_from_atomics(u128::MAX, 44), Ok(Decimal6::raw(3)));
+        assert_eq!(Decimal6::checked_from_atomics(u128::MAX, 45), Ok(Decimal6::ZERO));
+        assert_eq!(Decimal6::from_atomics(u128::MAX, u32::MAX), Ok(Decimal6::ZERO));
+    }
+
+    #[test]
+    fn test_try_from_atomics_i128() {
+        assert_eq!(Decimal6::try_from_atomics_i128(15, 1), Ok(Decimal6::from_str("1.5").unwrap()));
+        assert_eq!(Decimal6::try_from_atomics_i128(1_234_567_890, 9), Ok(Decimal6::raw(1_234_567)));
+        assert_eq!(Decimal6::try_from_atomics_i128(i128::MAX, 6), Ok(Decimal6::raw(i128::MAX as u128)));
+        assert!(matches!(
+            Decimal6::try_from_atomics_i128(-1, 6),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_atomics_rounded() {
+        use RoundingMode::*;
+        let atomics = 1_234_567_890u128;
+        assert_eq!(Decimal6::from_atomics_rounded(atomics, 9, Truncate), Ok(Decimal6::raw(1_234_567)));
+        assert_eq!(Decimal6::from_atomics_rounded(atomics, 9, HalfUp), Ok(Decimal6::raw(1_234_568)));
+        assert_eq!(Decimal6::from_atomics_rounded(atomics, 9, Ceil), Ok(Decimal6::raw(1_234_568)));
+        assert_eq!(Decimal6::from_atomics_rounded(2_500u128, 9, HalfEven), Ok(Decimal6::raw(2)));
+
+        // Scaling up ignores the mode and reports overflow
+        assert_eq!(Decimal6::from_atomics_rounded(15u128, 1, Ceil), Ok(Decimal6::raw(1_500_000)));
+        assert_eq!(
+            Decimal6::from_atomics_rounded(u128::MAX, 0, HalfUp),
+            Err(CustomDecimalError::Overflow)
+        );
+
+        // Huge decimal places round every value to zero or one unit
+        assert_eq!(Decimal6::from_atomics_rounded(u128::MAX, 200, HalfUp), Ok(Decimal6::ZERO));
+        assert_eq!(Decimal6::from_atomics_rounded(1u128, 200, Ceil), Ok(Decimal6::EPSILON));
+        assert_eq!(Decimal18::from_atomics_rounded(u128::MAX, 18, Ceil), Ok(Decimal18::MAX));
+    }
+
+    #[test]
+    fn test_from_atomics_exact() {
+        assert_eq!(
+            Decimal6::from_atomics_exact(1_234_567_890u128, 9),
+            Err(CustomDecimalError::PrecisionLoss)
+        );
+        assert_eq!(Decimal6::from_atomics_exact(1_234_000_000u128, 9), Ok(Decimal6::raw(1_234_000)));
+        assert_eq!(Decimal6::from_atomics_exact(15u128, 1), Ok(Decimal6::raw(1_500_000)));
+        assert_eq!(Decimal6::from_atomics_exact(0u128, 100), Ok(Decimal6::ZERO));
+        assert_eq!(Decimal6::from_atomics_exact(1u128, 100), Err(CustomDecimalError::PrecisionLoss));
+        assert_eq!(Decimal6::from_atomics_exact(u128::MAX, 0), Err(CustomDecimalError::Overflow));
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(Decimal::<6>::percent(0), Decimal::<6>::ZERO);
+        assert_eq!(Decimal::<6>::percent(50), Decimal::<6>::raw(500_000));
+        assert_eq!(Decimal::<6>::percent(100), Decimal::<6>::ONE);
+    }
+
+    #[test]
+    fn test_permille() {
+        assert_eq!(Decimal::<6>::permille(0), Decimal::<6>::ZERO);
+        assert_eq!(Decimal::<6>::permille(125), Decimal::<6>::raw(125_000));
+        assert_eq!(Decimal::<6>::permille(1000), Decimal::<6>::ONE);
+    }
+
+    #[test]
+    fn test_bps() {
+        assert_eq!(Decimal::<6>::bps(0), Decimal::<6>::ZERO);
+        assert_eq!(Decimal::<6>::bps(50), Decimal::<6>::raw(5_000));
+        assert_eq!(Decimal::<6>::bps(10000), Decimal::<6>::ONE);
+    }
+
+    #[test]
+    fn test_percent_low_precision() {
+        assert_eq!(Decimal::<1>::percent(50), Decimal::<1>::from_str("0.5").unwrap());
+        assert_eq!(Decimal::<1>::percent(55), Decimal::<1>::from_str("0.5").unwrap());
+        assert_eq!(Decimal::<1>::percent(100), Decimal::<1>::ONE);
+
+        // With no fractional digits, anything below 100% truncates to zero
+        assert_eq!(Decimal::<0>::percent(50), Decimal::<0>::ZERO);
+        assert_eq!(Decimal::<0>::percent(150), Decimal::<0>::ONE);
+
+        assert_eq!(Decimal::<2>::permille(125), Decimal::<2>::from_str("0.12").unwrap());
+        assert_eq!(Decimal::<2>::bps(50), Decimal::<2>::ZERO);
+        assert_eq!(Decimal::<2>::bps(150), Decimal::<2>::from_str("0.01").unwrap());
+    }
+
+    #[test]
+    fn test_from_ratio() {
+        let d = Decimal::<6>::from_ratio(3u128, 2u128);
+        assert_eq!(d, Decimal::<6>::from_str("1.5").unwrap());
+
+        let d = Decimal::<6>::from_ratio(1u128, 3u128);
+        assert_eq!(d.0, Uint128::new(333_333)); // 0.333333
+    }
+
+    #[test]
+    fn test_from_ratio_floor_ceil() {
+        assert_eq!(Decimal6::from_ratio_floor(1u128, 3u128), Decimal6::from_str("0.333333").unwrap());
+        assert_eq!(Decimal6::from_ratio_ceil(1u128, 3u128), Decimal6::from_str("0.333334").unwrap());
+        assert_eq!(Decimal6::from_ratio_ceil(2u128, 3u128), Decimal6::raw(666_667));
+        assert_eq!(Decimal6::from_ratio_floor(2u128, 3u128), Decimal6::from_ratio(2u128, 3u128));
+
+        // Exact ratios round identically
+        for (n, d) in [(3u128, 2u128), (0, 7), (7, 7), (1, 1_000_000), (5, 1000)] {
+            assert_eq!(Decimal6::from_ratio_floor(n, d), Decimal6::from_ratio_ceil(n, d));
+        }
+
+        // Dust below one unit still rounds up to EPSILON
+        assert_eq!(Decimal::<2>::from_ratio_ceil(1u128, 1000u128), Decimal::<2>::EPSILON);
+        assert_eq!(Decimal::<2>::from_ratio_floor(1u128, 1000u128), Decimal::<2>::ZERO);
+    }
+
+    #[test]
+    fn test_checked_from_ratio_floor_ceil() {
+        assert_eq!(Decimal6::checked_from_ratio_floor(1u128, 3u128), Ok(Decimal6::raw(333_333)));
+        assert_eq!(Decimal6::checked_from_ratio_ceil(1u128, 3u128), Ok(Decimal6::raw(333_334)));
+
+        assert_eq!(Decimal6::checked_from_ratio_ceil(1u128, 0u128), Err(CustomDecimalError::DivisionByZero));
+        assert_eq!(Decimal6::checked_from_ratio_floor(1u128, 0u128), Err(CustomDecimalError::DivisionByZero));
+        assert_eq!(Decimal6::checked_from_ratio_ceil(u128::MAX, 1u128), Err(CustomDecimalError::Overflow));
+        assert_eq!(Decimal6::checked_from_ratio_floor(u128::MAX, 1u128), Err(CustomDecimalError::Overflow));
+
+        // Rounding up can cross MAX where rounding down does not:
+        // n * 10 / 7 floors to exactly u128::MAX with remainder 5
+        let n = 238_197_656_844_656_924_424_362_225_202_237_748_019u128;
+        assert_eq!(Decimal::<1>::checked_from_ratio_floor(n, 7u128), Ok(Decimal::<1>::MAX));
+        assert_eq!(Decimal::<1>::checked_from_ratio_ceil(n, 7u128), Err(CustomDecimalError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn test_from_ratio_ceil_zero_denominator_panics() {
+        let _ = Decimal6::from_ratio_ceil(1u128, 0u128);
+    }
+
+    #[test]
+    fn test_from_ratio_const() {
+        const HALF: Decimal6 = Decimal6::from_ratio_const(1, 2);
+        const THIRD: Decimal18 = Decimal18::from_ratio_const(1, 3);
+        assert_eq!(HALF, Decimal6::percent(50));
+        assert_eq!(THIRD, Decimal18::from_ratio(1u128, 3u128));
+        assert_eq!(Decimal6::from_ratio_const(7, 7), Decimal6::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "ratio overflow in from_ratio_const")]
+    fn test_from_ratio_const_overflow_panics() {
+        // from_ratio handles this through Uint256; the const version cannot
+        assert_eq!(Decimal6::from_ratio(u128::MAX, u128::MAX), Decimal6::ONE);
+        let _ = Decimal6::from_ratio_const(u128::MAX, u128::MAX);
+    }
+
+    #[test]
+    fn test_saturating_from_ratio() {
+        assert_eq!(Decimal6::saturating_from_ratio(3u128, 2u128), Decimal6::from_ratio(3u128, 2u128));
+        assert_eq!(Decimal6::saturating_from_ratio(1u128, 3u128), Decimal6::raw(333_333));
+
+        // u128::MAX / 2 needs more than u128 atomics at 6 decimals
+        assert_eq!(Decimal6::saturating_from_ratio(u128::MAX, 2u128), Decimal6::MAX);
+        assert_eq!(Decimal6::saturating_from_ratio(1u128, 0u128), Decimal6::MAX);
+        assert_eq!(Decimal6::saturating_from_ratio(0u128, 0u128), Decimal6::ZERO);
+    }
+
+    #[test]
+    fn test_from_ratio_power_of_ten_denominator() {
+        // Exact: 3 / 10^3 at 6 decimals
+        let d = Decimal::<6>::from_ratio(3u128, 1000u128);
+        assert_eq!(d, Decimal::<6>::raw(3_000));
+        assert_eq!(d, Decimal::<6>::from_str("0.003").unwrap());
+
+        // Matches the general Uint256 path
+        let general = Uint256::from(3u128) * Uint256::from(Decimal::<6>::FRACTIONAL)
+            / Uint256::from(1000u128);
+        assert_eq!(Uint256::from(d.0), general);
+
+        // k == D is still exact
+        assert_eq!(
+            Decimal::<6>::from_ratio(1u128, 1_000_000u128),
+            Decimal::<6>::raw(1)
+        );
+
+        // k > D falls back to the general (truncating) path
+        assert_eq!(Decimal::<2>::from_ratio(15u128, 1000u128), Decimal::<2>::raw(1));
+
+        assert_eq!(pow10_exponent(1), Some(0));
+        assert_eq!(pow10_exponent(1000), Some(3));
+        assert_eq!(pow10_exponent(1200), None);
+        assert_eq!(pow10_exponent(0), None);
+    }
+
+    #[test]
+    fn test_checked_from_ratio_nonzero() {
+        // Collapses to zero at 2 decimals
+        assert_eq!(
+            Decimal::<2>::checked_from_ratio_nonzero(1u128, 1000u128),
+            Err(CustomDecimalError::PrecisionLoss)
+        );
+        assert_eq!(Decimal::<2>::from_ratio(1u128, 1000u128), Decimal::<2>::ZERO);
+
+        // Representable at 6 decimals
+        assert_eq!(
+            Decimal::<6>::checked_from_ratio_nonzero(1u128, 1000u128),
+            Ok(Decimal::<6>::raw(1_000))
+        );
+
+        // A zero numerator is not precision loss
+        assert_eq!(
+            Decimal::<2>::checked_from_ratio_nonzero(0u128, 1000u128),
+            Ok(Decimal::<2>::ZERO)
+        );
+
+        assert_eq!(
+            Decimal::<6>::checked_from_ratio_nonzero(1u128, 0u128),
+            Err(CustomDecimalError::DivisionByZero)
+        );
+        assert_eq!(
+            Decimal::<6>::checked_from_ratio_nonzero(u128::MAX, 1u128),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_units() {
+        let units = [("k", 3), ("M", 6), ("m", -3), ("u", -6), ("bps", -4)];
+
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("1.5k", &units).unwrap(),
+            Decimal::<6>::from(1500u64)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("2M", &units).unwrap(),
+            Decimal::<6>::from(2_000_000u64)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("5m", &units).unwrap(),
+            Decimal::<6>::from_str("0.005").unwrap()
+        );
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("3u", &units).unwrap(),
+            Decimal::<6>::raw(3)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("25 bps", &units).unwrap(),
+            Decimal::<6>::bps(25)
+        );
+        assert_eq!(
+            Decimal::<2>::from_str_with_units("1500m", &units).unwrap(),
+            Decimal::<2>::from_str("1.5").unwrap()
+        );
+
+        // No suffix parses as a plain number
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("1.25", &units).unwrap(),
+            Decimal::<6>::from_str("1.25").unwrap()
+        );
+
+        // Unknown suffix
+        assert!(matches!(
+            Decimal::<6>::from_str_with_units("1.5x", &units),
+            Err(CustomDecimalError::ParseError(_))
+        ));
+        // Too precise after applying the exponent
+        assert!(matches!(
+            Decimal::<6>::from_str_with_units("0.5u", &units),
+            Err(CustomDecimalError::ParseError(_))
+        ));
+        // Missing number
+        assert!(Decimal::<6>::from_str_with_units("k", &units).is_err());
+        // Overflow
+        assert_eq!(
+            Decimal::<6>::from_str_with_units("1000000000000000000000000000000000M", &units),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Decimal::<6>::from_str("1.5").unwrap(),
+            Decimal::<6>::raw(1_500_000)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str("123").unwrap(),
+            Decimal::<6>::raw(123_000_000)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str("0.123456").unwrap(),
+            Decimal::<6>::raw(123_456)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Decimal::<6>::raw(1_500_000).to_string(), "1.5");
+        assert_eq!(Decimal::<6>::raw(1_000_000).to_string(), "1");
+        assert_eq!(Decimal::<6>::raw(123_456).to_string(), "0.123456");
+        assert_eq!(Decimal::<6>::raw(100_000).to_string(), "0.1");
+    }
+
+    #[test]
+    fn test_display_precision_and_width() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        assert_eq!(format!("{:.2}", d), "1.50");
+        assert_eq!(format!("{:.0}", d), "2");
+        assert_eq!(format!("{:.8}", d), "1.50000000");
+        assert_eq!(format!("{:>8}", d), "     1.5");
+        assert_eq!(format!("{:<8}|", d), "1.5     |");
+        assert_eq!(format!("{:*^7}", d), "**1.5**");
+        assert_eq!(format!("{:08.2}", d), "00001.50");
+        assert_eq!(format!("{:+}", d), "+1.5");
+        assert_eq!(format!("{:>8.3}", d), "   1.500");
+
+        // Half-up rounding at the requested precision
+        let d = Decimal6::from_str("2.345").unwrap();
+        assert_eq!(format!("{:.2}", d), "2.35");
+        assert_eq!(format!("{:.1}", Decimal6::from_str("9.96").unwrap()), "10.0");
+
+        // Precisions beyond 38 digits still print exactly that many
+        let d = Decimal6::from_str("1.5").unwrap();
+        assert_eq!(format!("{:.40}", d), format!("1.5{}", "0".repeat(39)));
+        assert_eq!(format!("{:.1$}", d, 1_000).len(), 1_002);
+        assert_eq!(format!("{:>44.40}|", d), format!("  1.5{}|", "0".repeat(39)));
+        assert_eq!(format!("{:<44.40}|", d), format!("1.5{}  |", "0".repeat(39)));
+        assert_eq!(format!("{:+044.40}", d), format!("+01.5{}", "0".repeat(39)));
+        assert_eq!(format!("{:.40}", Decimal::<0>::from(7u64)), format!("7.{}", "0".repeat(40)));
+        let negative = SignedDecimal::<6>::from_str("-1.5").unwrap();
+        assert_eq!(format!("{:.40}", negative), format!("-1.5{}", "0".repeat(39)));
+
+        // Plain formatting is unchanged
+        assert_eq!(format!("{}", Decimal6::from_str("1.230").unwrap()), "1.23");
+        assert_eq!(format!("{}", Decimal::<0>::from(7u64)), "7");
+    }
+
+    #[test]
+    fn test_scientific_formatting() {
+        assert_eq!(format!("{:e}", Decimal6::raw(1_500_000)), "1.5e0");
+        assert_eq!(format!("{:e}", Decimal6::from_str("0.000001").unwrap()), "1e-6");
+        assert_eq!(format!("{:e}", Decimal6::ZERO), "0e0");
+        assert_eq!(format!("{:E}", Decimal6::from(1_200_000u64)), "1.2E6");
+        assert_eq!(format!("{:e}", Decimal6::from_str("0.00123").unwrap()), "1.23e-3");
+        assert_eq!(format!("{:e}", Decimal6::MAX), "3.40282366920938463463374607431768211455e32");
+        assert_eq!(format!("{:e}", Decimal::<0>::from(100u64)), "1e2");
+
+        // Precision rounds the mantissa half-up, carrying into the exponent
+        let d = Decimal6::from_str("12.345").unwrap();
+        assert_eq!(format!("{:.2e}", d), "1.23e1");
+        assert_eq!(format!("{:.3e}", d), "1.235e1");
+        assert_eq!(format!("{:.5e}", d), "1.23450e1");
+        assert_eq!(format!("{:.1e}", Decimal6::from_str("9.96").unwrap()), "1.0e1");
+        assert_eq!(format!("{:.0e}", Decimal6::from_str("0.5").unwrap()), "5e-1");
+        assert_eq!(format!("{:.2e}", Decimal6::ZERO), "0.00e0");
+        // Precisions beyond 38 digits still print exactly that many
+        assert_eq!(format!("{:.40e}", Decimal6::ONE), format!("1.{}e0", "0".repeat(40)));
+        assert_eq!(
+            format!("{:.40E}", Decimal6::MAX),
+            "3.4028236692093846346337460743176821145500E32"
+        );
+        assert_eq!(format!("{:.1$e}", Decimal6::ONE, 1_000).len(), 1_004);
+        assert_eq!(format!("{:>46.40e}|", Decimal6::ONE), format!("  1.{}e0|", "0".repeat(40)));
+
+        assert_eq!(format!("{:>8e}", Decimal6::raw(1_500_000)), "   1.5e0");
+
+        // The output parses back to the same value
+        for s in ["1.5", "0.000001", "123456.789", "0"] {
+            let d = Decimal6::from_str(s).unwrap();
+            assert_eq!(Decimal6::from_str(&format!("{:e}", d)).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn test_integer_and_fractional_part() {
+        let d = Decimal6::from_str("12.345").unwrap();
+        assert_eq!(d.integer_part(), Uint128::new(12));
+        assert_eq!(d.fractional_part(), Decimal6::from_str("0.345").unwrap());
+
+        for value in [Decimal6::ZERO, Decimal6::ONE, Decimal6::EPSILON, Decimal6::MAX, d] {
+            assert_eq!(Decimal6::from(value.integer_part()) + value.fractional_part(), value);
+            assert!(value.fractional_part() < Decimal6::ONE);
+        }
+        assert_eq!(Decimal6::ONE.fractional_part(), Decimal6::ZERO);
+        assert_eq!(Decimal6::EPSILON.integer_part(), Uint128::zero());
+    }
+
+    #[test]
+    fn test_floor_ceil() {
+        let d = Decimal::<6>::from_str("1.7").unwrap();
+        assert_eq!(d.floor(), Decimal::<6>::from_str("1.0").unwrap());
+        assert_eq!(d.ceil(), Decimal::<6>::from_str("2.0").unwrap());
+
+        let d = Decimal::<6>::from_str("2.0").unwrap();
+        assert_eq!(d.floor(), d);
+        assert_eq!(d.ceil(), d);
+    }
+
+    #[test]
+    fn test_checked_ceil_near_max() {
+        let d = Decimal::<6>::from_str("1.5").unwrap();
+        assert_eq!(d.checked_ceil(), Some(Decimal::<6>::from_str("2.0").unwrap()));
+        assert_eq!(d.checked_floor(), Some(Decimal::<6>::ONE));
+
+        assert_eq!(Decimal::<6>::MAX.checked_ceil(), None);
+        assert_eq!(
+            Decimal::<6>::MAX.checked_floor(),
+            Some(Decimal::<6>::MAX.floor())
+        );
+
+        // An integral value at the top of the range is its own ceiling
+        let top = Decimal::<6>::MAX.floor();
+        assert_eq!(top.checked_ceil(), Some(top));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_ceil_overflow_panics() {
+        let _ = Decimal::<6>::MAX.ceil();
+    }
+
+    #[test]
+    fn test_round_to() {
+        let d = Decimal18::from_str("1.23456789").unwrap();
+        assert_eq!(d.round_to(4), Decimal18::from_str("1.2346").unwrap());
+        assert_eq!(d.round_to(0), Decimal18::ONE);
+
+        // Ties round up
+        let d = Decimal::<6>::from_str("2.125").unwrap();
+        assert_eq!(d.round_to(2), Decimal::<6>::from_str("2.13").unwrap());
+        let d = Decimal::<6>::from_str("0.5").unwrap();
+        assert_eq!(d.round_to(0), Decimal::<6>::ONE);
+
+        // No-op at or beyond the type precision
+        let d = Decimal::<6>::from_str("1.123456").unwrap();
+        assert_eq!(d.round_to(6), d);
+        assert_eq!(d.round_to(10), d);
+
+        // MAX atomics end in ...211455, so the last digit rounds up past MAX
+        assert_eq!(Decimal::<6>::MAX.checked_round_to(5), None);
+        assert_eq!(
+            Decimal::<6>::MAX.checked_round_to(2),
+            Some(Decimal::<6>::raw(u128::MAX / 10_000 * 10_000))
+        );
+        assert_eq!(Decimal::<6>::MAX.checked_round_to(6), Some(Decimal::<6>::MAX));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let d = Decimal::<6>::from_str("4.0").unwrap();
+        assert_eq!(d.sqrt(), Decimal::<6>::from_str("2.0").unwrap());
+
+        let d = Decimal::<6>::from_str("9.0").unwrap();
+        assert_eq!(d.sqrt(), Decimal::<6>::from_str("3.0").unwrap());
+    }
+
+    #[test]
+    fn test_sqrt_high_precision() {
+        let four = Decimal::<24>::from(4u64);
+        assert_eq!(four.sqrt(), Decimal::<24>::from(2u64));
+
+        // sqrt(2) = 1.414213562373095048801688724209..
+        let root2 = Decimal::<24>::from(2u64).sqrt();
+        assert_eq!(root2.to_string(), "1.414213562373095048801688");
+        // A round trip through StdDecimal keeps only 18 places
+        let via_std = Decimal::<24>::from(StdDecimal::from(root2));
+        assert_ne!(via_std, root2);
+
+        assert_eq!(Decimal::<38>::ONE.sqrt(), Decimal::<38>::ONE);
+        assert_eq!(
+            Decimal::<38>::from_str("0.25").unwrap().checked_sqrt(),
+            Some(Decimal::<38>::from_str("0.5").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_sqrt_truncates_and_covers_full_range() {
+        // sqrt(2) at 6 places truncates 1.4142135.. to 1.414213
+        assert_eq!(Decimal6::from(2u64).sqrt(), Decimal6::from_str("1.414213").unwrap());
+        assert_eq!(Decimal6::ZERO.sqrt(), Decimal6::ZERO);
+        assert_eq!(Decimal6::raw(1).sqrt(), Decimal6::from_str("0.001").unwrap());
+
+        // MAX no longer needs to fit in StdDecimal's 18 places
+        let root = Decimal6::MAX.sqrt();
+        assert!(root.checked_mul(root).unwrap() <= Decimal6::MAX);
+        assert!(Decimal6::MAX.checked_sqrt().is_some());
+    }
+
+    #[test]
+    fn test_pow() {
+        let d = Decimal::<6>::from_str("2.0").unwrap();
+        assert_eq!(d.pow(0), Decimal::<6>::ONE);
+        assert_eq!(d.pow(1), d);
+        assert_eq!(d.pow(3), Decimal::<6>::from_str("8.0").unwrap());
+    }
+
+    #[test]
+    fn test_max_decimal_places() {
+        assert_eq!(pow10(MAX_DECIMAL_PLACES), 10u128.pow(38));
+        assert_eq!(Decimal::<38>::FRACTIONAL, 10u128.pow(38));
+        assert_eq!(Decimal::<38>::ONE.atomics(), 10u128.pow(38));
+        assert_eq!(Decimal::<0>::ONE.atomics(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "10^exp overflows u128")]
+    fn test_pow10_overflow_panics() {
+        let exp = std::hint::black_box(MAX_DECIMAL_PLACES + 1);
+        let _ = pow10(exp);
+    }
+
+    #[test]
+    fn test_fraction() {
+        let quarter = Decimal::<6>::percent(25);
+        assert_eq!(quarter.numerator(), Uint128::new(250_000));
+        assert_eq!(quarter.denominator(), Uint128::new(1_000_000));
+        assert_eq!(Fraction::inv(&quarter), Some(Decimal::<6>::from_str("4.0").unwrap()));
+
+        // Reciprocals truncate to D places
+        let three = Decimal::<6>::from(3u64);
+        assert_eq!(Fraction::inv(&three), Some(Decimal::<6>::from_str("0.333333").unwrap()));
+
+        assert_eq!(Fraction::inv(&Decimal::<6>::ZERO), None);
+        assert_eq!(
+            Fraction::inv(&Decimal::<6>::raw(1)),
+            Some(Decimal::<6>::from(1_000_000u64))
+        );
+
+        // 10^20 does not fit in Decimal<20>
+        assert_eq!(Fraction::inv(&Decimal::<20>::raw(1)), None);
+        assert_eq!(Fraction::inv(&Decimal::<20>::ONE), Some(Decimal::<20>::ONE));
+    }
+
+    #[test]
+    fn test_inv() {
+        assert_eq!(Decimal::<6>::percent(25).inv(), Decimal::<6>::from_str("4.0").unwrap());
+        assert_eq!(Decimal::<6>::ONE.inv(), Decimal::<6>::ONE);
+        assert_eq!(Decimal::<6>::ZERO.checked_inv(), None);
+
+        // Same truncation as dividing ONE directly
+        for s in ["3", "7", "0.000007", "123.456789"] {
+            let x = Decimal::<6>::from_str(s).unwrap();
+            assert_eq!(x.inv(), Decimal::<6>::ONE / x);
+            assert_eq!(x.checked_inv(), Fraction::inv(&x));
+        }
+
+        assert_eq!(Decimal::<20>::raw(1).checked_inv(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_inv_zero_panics() {
+        let _ = Decimal::<6>::ZERO.inv();
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Decimal::<6>::from_str("1.5").unwrap().to_f64(), 1.5);
+        assert_eq!(Decimal::<6>::ZERO.to_f64(), 0.0);
+        assert_eq!(Decimal::<18>::from_str("0.25").unwrap().to_f64(), 0.25);
+        assert_eq!(Decimal::<0>::from(42u64).to_f64(), 42.0);
+
+        // Lossy beyond f64 precision
+        let max = Decimal::<6>::MAX.to_f64();
+        assert!((max - u128::MAX as f64 / 1e6).abs() / max < 1e-15);
+    }
+
+    #[test]
+    fn test_try_from_f64() {
+        assert_eq!(Decimal::<6>::try_from_f64(1.5), Ok(Decimal::<6>::from_str("1.5").unwrap()));
+        assert_eq!(Decimal::<6>::try_from_f64(0.0), Ok(Decimal::<6>::ZERO));
+        assert_eq!(Decimal::<6>::try_from_f64(-0.0), Ok(Decimal::<6>::ZERO));
+
+        // Rounds to nearest at D places
+        assert_eq!(Decimal::<6>::try_from_f64(0.1), Ok(Decimal::<6>::from_str("0.1").unwrap()));
+        assert_eq!(Decimal::<2>::try_from_f64(1.006), Ok(Decimal::<2>::from_str("1.01").unwrap()));
+        assert_eq!(Decimal::<2>::try_from_f64(1.004), Ok(Decimal::<2>::from_str("1.0").unwrap()));
+
+        assert!(matches!(
+            Decimal::<6>::try_from_f64(f64::NAN),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+        assert!(matches!(
+            Decimal::<6>::try_from_f64(f64::INFINITY),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+        assert!(matches!(
+            Decimal::<6>::try_from_f64(-1.5),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+        assert_eq!(Decimal::<6>::try_from_f64(1e33), Err(CustomDecimalError::Overflow));
+        assert!(Decimal::<6>::try_from_f64(1e32).is_ok());
+    }
+
+    #[test]
+    fn test_from_str_shorthand() {
+        assert_eq!(Decimal6::from_str(".5").unwrap(), Decimal6::raw(500_000));
+        assert_eq!(Decimal6::from_str("1.").unwrap(), Decimal6::ONE);
+        assert_eq!(Decimal6::from_str("+.000001").unwrap(), Decimal6::EPSILON);
+        assert_eq!(Decimal::<0>::from_str("7.").unwrap(), Decimal::<0>::from(7u64));
+
+        assert!(Decimal6::from_str(".").is_err());
+        assert!(Decimal6::from_str("..5").is_err());
+        assert!(Decimal6::from_str(".1234567").is_err());
+    }
+
+    #[test]
+    fn test_from_str_error_messages() {
+        let message = |s: &str| match Decimal6::from_str(s) {
+            Err(CustomDecimalError::ParseError(msg)) => msg,
+            other => panic!("expected a parse error for {:?}, got {:?}", s, other),
+        };
+
+        assert_eq!(message(""), "empty string");
+        assert_eq!(message("+"), "empty string");
+        assert_eq!(message("-1.5"), "negative values are not supported; use SignedDecimal");
+        assert_eq!(message("-0"), "negative values are not supported; use SignedDecimal");
+        assert_eq!(message("."), "Invalid decimal format: .");
+        assert_eq!(message("1.+5"), "Invalid fractional: +5");
+        assert_eq!(message("+1.+5"), "Invalid fractional: +5");
+        assert_eq!(message(".+5"), "Invalid fractional: +5");
+    }
+
+    #[test]
+    fn test_try_from_str_and_string() {
+        let d: Decimal6 = "1.5".try_into().unwrap();
+        assert_eq!(d, Decimal6::raw(1_500_000));
+
+        let d = Decimal6::try_from(String::from("0.25")).unwrap();
+        assert_eq!(d, Decimal6::raw(250_000));
+
+        let err: Result<Decimal6, _> = "abc".try_into();
+        assert!(matches!(err, Err(CustomDecimalError::ParseError(_))));
+        assert!(matches!(
+            Decimal6::try_from(String::from("1.2.3")),
+            Err(CustomDecimalError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_scientific_notation() {
+        assert_eq!(Decimal::<9>::from_str("1.5e-6").unwrap(), Decimal::<9>::raw(1_500));
+        assert_eq!(Decimal::<6>::from_str("1e-6").unwrap(), Decimal::<6>::raw(1));
+        assert_eq!(Decimal::<6>::from_str("2.5E3").unwrap(), Decimal::<6>::from(2500u64));
+        assert_eq!(Decimal::<6>::from_str("2.5e+3").unwrap(), Decimal::<6>::from(2500u64));
+        assert_eq!(Decimal::<6>::from_str("15e-1").unwrap(), Decimal::<6>::from_str("1.5").unwrap());
+        assert_eq!(Decimal::<6>::from_str("0.00e5").unwrap(), Decimal::<6>::ZERO);
+        assert_eq!(Decimal::<0>::from_str("1.5e1").unwrap(), Decimal::<0>::from(15u64));
+
+        // More fractional digits than D can hold
+        assert!(matches!(
+            Decimal::<6>::from_str("1.1234567e0"),
+            Err(CustomDecimalError::ParseError(_))
+        ));
+        assert!(matches!(
+            Decimal::<6>::from_str("1.5e-6"),
+            Err(CustomDecimalError::ParseError(_))
+        ));
+        assert_eq!(Decimal::<6>::from_str("1e40"), Err(CustomDecimalError::Overflow));
+
+        for bad in ["1e", "e5", "1.5e1.5", "1e5e5", "1.2.3e1", "1ex"] {
+            assert!(
+                matches!(Decimal::<6>::from_str(bad), Err(CustomDecimalError::ParseError(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_digit_separators() {
+        assert_eq!(
+            Decimal::<6>::from_str("1_000.250_000").unwrap(),
+            Decimal::<6>::raw(1_000_250_000)
+        );
+        assert_eq!(
+            Decimal::<6>::from_str("1_000_000.5").unwrap(),
+            Decimal::<6>::from_str("1000000.5").unwrap()
+        );
+        assert_eq!(Decimal::<6>::from_str("1_5e-1").unwrap(), Decimal::<6>::from_str("1.5").unwrap());
+
+        for bad in ["1__0", "_1", "1_", "1_.5", "1._5", "_", "1_e5"] {
+            assert!(
+                matches!(Decimal::<6>::from_str(bad), Err(CustomDecimalError::ParseError(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_leading_plus() {
+        assert_eq!(Decimal::<6>::from_str("+3.5").unwrap(), Decimal::<6>::from_str("3.5").unwrap());
+        assert_eq!(Decimal::<6>::from_str("+7").unwrap(), Decimal::<6>::from(7u64));
+        assert_eq!(Decimal::<6>::from_str("+1_000").unwrap(), Decimal::<6>::from(1000u64));
+
+        for bad in ["++1", "+-1", "+", "+_1", "1.+5", "+1.+5", ".+5"] {
+            assert!(
+                matches!(Decimal::<6>::from_str(bad), Err(CustomDecimalError::ParseError(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_schema_describes_decimal() {
+        use cosmwasm_schema::schema_for;
+        let schema = serde_json::to_value(schema_for!(Decimal6)).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["format"], "decimal");
+        // StdDecimal-style strings carry up to 18 digits whatever D is
+        assert_eq!(schema["pattern"], "^[0-9]+(\\.[0-9]{1,18})?$");
+        let description = schema["description"].as_str().unwrap();
+        assert!(description.contains("6 decimal places"));
+        assert!(description.contains("cosmwasm_std::Decimal"));
+
+        assert_eq!(Decimal6::schema_name(), "Decimal6");
+        assert_ne!(Decimal6::schema_id(), Decimal18::schema_id());
+        let whole = serde_json::to_value(schema_for!(Decimal::<0>)).unwrap();
+        assert_eq!(whole["pattern"], schema["pattern"]);
+
+        let signed = serde_json::to_value(schema_for!(SignedDecimal<6>)).unwrap();
+        assert_eq!(signed["type"], "string");
+        assert_eq!(signed["format"], "decimal");
+        assert_eq!(signed["pattern"], "^-?[0-9]+(\\.[0-9]{1,18})?$");
+        assert!(signed["description"].as_str().unwrap().contains("6 decimal places"));
+        assert_eq!(SignedDecimal::<6>::schema_name(), "SignedDecimal6");
+        assert_ne!(SignedDecimal::<6>::schema_id(), SignedDecimal::<18>::schema_id());
+    }
+
+    #[test]
+    fn test_hash_deduplicates_equal_values() {
+        use std::collections::HashSet;
+
+        let values: HashSet<Decimal<6>> = ["1.5", "1.500000", "2", "2.0", "0.000001"]
+            .iter()
+            .map(|s| Decimal::<6>::from_str(s).unwrap())
+            .collect();
+        assert_eq!(values.len(), 3);
+        assert!(values.contains(&Decimal::<6>::raw(1_500_000)));
+        assert!(values.contains(&Decimal::<6>::from(2u64)));
+    }
+
+    fn iterative_pow(base: Decimal<6>, exp: u32) -> Option<Decimal<6>> {
+        let mut result = Decimal::<6>::ONE;
+        for _ in 0..exp {
+            result = result.checked_mul(base)?;
+        }
+        Some(result)
+    }
+
+    #[test]
+    fn test_checked_neg() {
+        assert_eq!(Decimal6::ZERO.checked_neg(), Some(Decimal6::ZERO));
+        assert_eq!(Decimal6::ONE.checked_neg(), None);
+        assert_eq!(Decimal6::EPSILON.checked_neg(), None);
+    }
+
+    #[test]
+    fn test_checked_signed_sub() {
+        let two = Decimal6::from(2u64);
+        let five = Decimal6::from(5u64);
+
+        let diff = two.checked_signed_sub(five).unwrap();
+        assert!(diff.is_negative());
+        assert_eq!(diff.magnitude(), Decimal6::from(3u64));
+
+        let diff = five.checked_signed_sub(two).unwrap();
+        assert!(!diff.is_negative());
+        assert_eq!(Decimal6::try_from(diff).unwrap(), five - two);
+
+        assert_eq!(two.checked_signed_sub(two), Some(SignedDecimal::ZERO));
+        assert_eq!(Decimal6::ZERO.checked_signed_sub(Decimal6::MAX), Some(SignedDecimal::MIN));
+    }
+
+    #[test]
+    fn test_checked_pow_by_squaring() {
+        // Exact (integer) powers agree with repeated multiplication, overflow included
+        for base in ["2", "3", "10"] {
+            let base = Decimal::<6>::from_str(base).unwrap();
+            for exp in 0..=120 {
+                assert_eq!(base.checked_pow(exp), iterative_pow(base, exp), "{}^{}", base, exp);
+            }
+        }
+
+        // 14 squarings instead of 10_000 multiplies. Both round down at every step,
+        // and the fewer truncations keep squaring closer to e^0.99995 ≈ 2.718146
+        let base = Decimal::<6>::from_str("1.0001").unwrap();
+        let squared = base.checked_pow(10_000).unwrap();
+        let iterative = iterative_pow(base, 10_000).unwrap();
+        assert_eq!(squared, Decimal::<6>::from_str("2.715366").unwrap());
+        assert_eq!(iterative, Decimal::<6>::from_str("2.709573").unwrap());
+        assert!(iterative <= squared && squared <= Decimal::<6>::from_str("2.718146").unwrap());
+
+        // Huge exponents overflow or settle immediately instead of looping
+        assert_eq!(Decimal::<6>::from_str("2").unwrap().checked_pow(1_000_000), None);
+        assert_eq!(Decimal::<6>::ONE.checked_pow(u32::MAX), Some(Decimal::<6>::ONE));
+        assert_eq!(Decimal::<6>::ZERO.checked_pow(u32::MAX), Some(Decimal::<6>::ZERO));
+    }
+
+    #[test]
+    fn test_abs_diff_capped() {
+        let a = Decimal::<6>::from_str("1.05").unwrap();
+        let b = Decimal::<6>::from_str("1.0").unwrap();
+        let cap = Decimal::<6>::from_str("0.1").unwrap();
+
+        // Within the cap
+        assert_eq!(a.abs_diff_capped(b, cap), Decimal::<6>::from_str("0.05").unwrap());
+        assert_eq!(b.abs_diff_capped(a, cap), Decimal::<6>::from_str("0.05").unwrap());
+
+        // Above the cap
+        let c = Decimal::<6>::from_str("2.0").unwrap();
+        assert_eq!(c.abs_diff_capped(b, cap), cap);
+
+        assert_eq!(Decimal::<6>::MAX.saturating_abs_diff(Decimal::<6>::ZERO), Decimal::<6>::MAX);
+        assert_eq!(Decimal::<6>::ZERO.saturating_abs_diff(Decimal::<6>::MAX), Decimal::<6>::MAX);
+    }
+
+    #[test]
+    fn test_value_eq_across_precisions() {
+        let d6 = Decimal6::from_str("1.5").unwrap();
+        let d9 = Decimal9::from_str("1.5").unwrap();
+        assert!(d6.value_eq(&d9));
+        assert!(d9.value_eq(&d6));
+        assert_eq!(d6.value_cmp(&d9), Ordering::Equal);
+
+        // Equal within 6 decimals, but not exactly
+        let fine = Decimal9::from_str("1.123456789").unwrap();
+        let coarse = Decimal6::from_str("1.123456").unwrap();
+        assert!(fine.value_eq(&coarse));
+        assert!(coarse.value_eq(&fine));
+        assert_eq!(fine.value_cmp(&coarse), Ordering::Greater);
+        assert_eq!(coarse.value_cmp(&fine), Ordering::Less);
+        assert_ne!(coarse.to_precision::<9>(), fine);
+
+        assert!(!Decimal6::from_str("1.123457").unwrap().value_eq(&fine));
+        assert!(Decimal6::MAX.value_eq(&Decimal6::MAX));
+        assert_eq!(Decimal6::MAX.value_cmp(&Decimal18::MAX), Ordering::Greater);
+        assert_eq!(Decimal::<0>::MAX.value_cmp(&Decimal::<38>::MAX), Ordering::Greater);
+        assert_eq!(Decimal18::ONE.value_cmp(&Decimal::<0>::ONE), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let one = Decimal::<6>::ONE;
+        let three = Decimal::<6>::from(3u64);
+        let almost_one = one / three * three;
+        assert_ne!(almost_one, one);
+        assert!(almost_one.approx_eq(one, Decimal::<6>::raw(1)));
+        assert!(one.approx_eq(almost_one, Decimal::<6>::raw(1)));
+        assert!(!almost_one.approx_eq(one, Decimal::<6>::ZERO));
+
+        // Identical values are equal at zero tolerance
+        assert!(one.approx_eq(one, Decimal::<6>::ZERO));
+        assert!(Decimal::<6>::MAX.approx_eq_ulps(Decimal::<6>::MAX, 0));
+
+        assert!(almost_one.approx_eq_ulps(one, 1));
+        assert!(!Decimal::<6>::raw(10).approx_eq_ulps(Decimal::<6>::raw(13), 2));
+        assert!(Decimal::<6>::ZERO.approx_eq_ulps(Decimal::<6>::MAX, u128::MAX));
+    }
+
+    #[test]
+    fn test_min_max_match_ord() {
+        let pairs = [
+            (Decimal6::ZERO, Decimal6::ONE),
+            (Decimal6::ONE, Decimal6::ZERO),
+            (Decimal6::percent(50), Decimal6::percent(50)),
+            (Decimal6::MAX, Decimal6::EPSILON),
+            (Decimal6::MAX, Decimal6::MAX),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.min(b), Ord::min(a, b));
+            assert_eq!(a.min(b), core::cmp::min(a, b));
+            assert_eq!(a.max(b), Ord::max(a, b));
+            assert_eq!(a.max(b), core::cmp::max(a, b));
+            assert!(a.min(b) <= a.max(b));
+        }
+    }
+
+    #[test]
+    fn test_clamp() {
+        let lo = Decimal::<6>::from_str("0.01").unwrap();
+        let hi = Decimal::<6>::from_str("0.1").unwrap();
+
+        let below = Decimal::<6>::from_str("0.001").unwrap();
+        let above = Decimal::<6>::from_str("0.5").unwrap();
+        let within = Decimal::<6>::from_str("0.05").unwrap();
+        assert_eq!(below.clamp(lo, hi), lo);
+        assert_eq!(above.clamp(lo, hi), hi);
+        assert_eq!(within.clamp(lo, hi), within);
+        assert_eq!(within.clamp(within, within), within);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp requires min <= max")]
+    fn test_clamp_inverted_bounds_panics() {
+        let _ = Decimal::<6>::ONE.clamp(Decimal::<6>::ONE, Decimal::<6>::ZERO);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let two = Decimal::<6>::from(2u64);
+        let four = Decimal::<6>::from(4u64);
+        assert_eq!(two.midpoint(four), Decimal::<6>::from(3u64));
+        assert_eq!(four.midpoint(two), Decimal::<6>::from(3u64));
+
+        assert_eq!(Decimal::<6>::MAX.midpoint(Decimal::<6>::MAX), Decimal::<6>::MAX);
+        assert_eq!(
+            Decimal::<6>::MAX.midpoint(Decimal::<6>::ZERO),
+            Decimal::<6>::raw(u128::MAX / 2)
+        );
+
+        // Odd atomic sums round down
+        assert_eq!(Decimal::<6>::raw(1).midpoint(Decimal::<6>::raw(2)), Decimal::<6>::raw(1));
+        assert_eq!(Decimal::<6>::raw(3).midpoint(Decimal::<6>::raw(3)), Decimal::<6>::raw(3));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let zero = Decimal::<6>::ZERO;
+        let ten = Decimal::<6>::from(10u64);
+        let quarter = Decimal::<6>::from_str("0.25").unwrap();
+
+        assert_eq!(zero.lerp(ten, quarter), Decimal::<6>::from_str("2.5").unwrap());
+        assert_eq!(ten.lerp(zero, quarter), Decimal::<6>::from_str("7.5").unwrap());
+        assert_eq!(ten.lerp(zero, Decimal::<6>::ZERO), ten);
+        assert_eq!(ten.lerp(zero, Decimal::<6>::ONE), zero);
+
+        // t above one clamps to the far endpoint
+        assert_eq!(zero.lerp(ten, Decimal::<6>::from(2u64)), ten);
+
+        // Large endpoints don't overflow
+        let max = Decimal::<6>::MAX;
+        assert_eq!(max.lerp(max, quarter), max);
+        assert_eq!(max.lerp(zero, Decimal::<6>::from_str("0.5").unwrap()), max.midpoint(zero));
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        let values: Vec<Decimal6> = ["1.000001", "2.000001", "3.000001"]
+            .iter()
+            .map(|v| Decimal6::from_str(v).unwrap())
+            .collect();
+        let weights = [Uint128::one(); 3];
+        let exact = Decimal6::from_str("2.000001").unwrap();
+
+        // Naive: scale each value by its share of the total weight, then sum
+        let share = Decimal6::from_ratio(1u128, 3u128);
+        let naive: Decimal6 = values.iter().map(|v| *v * share).sum();
+        assert_eq!(naive, Decimal6::from_str("1.999998").unwrap());
+
+        let fused = Decimal6::weighted_average(&values, &weights).unwrap();
+        assert_eq!(fused, exact);
+        assert!(fused.abs_diff(exact) < naive.abs_diff(exact));
+
+        let weights = [Uint128::new(1), Uint128::new(0), Uint128::new(3)];
+        assert_eq!(
+            Decimal6::weighted_average(&values, &weights),
+            Some(Decimal6::from_str("2.500001").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_weighted_average_invalid_inputs() {
+        let values = [Decimal6::ONE, Decimal6::MAX];
+        assert_eq!(Decimal6::weighted_average(&values, &[Uint128::one()]), None);
+        assert_eq!(Decimal6::weighted_average(&values, &[Uint128::zero(); 2]), None);
+        assert_eq!(Decimal6::weighted_average(&[], &[]), None);
+
+        // Large values with u64 weights fit comfortably in Uint256
+        let weights = [Uint128::from(u64::MAX); 2];
+        assert_eq!(
+            Decimal6::weighted_average(&[Decimal6::MAX; 2], &weights),
+            Some(Decimal6::MAX)
+        );
+        // Two MAX * Uint128::MAX products exceed Uint256
+        let weights = [Uint128::MAX; 2];
+        assert_eq!(Decimal6::weighted_average(&[Decimal6::MAX; 2], &weights), None);
+    }
+
+    #[test]
+    fn test_try_sum_try_product() {
+        let values: Vec<Decimal6> = ["1.5", "2.25", "0.5"]
+            .iter()
+            .map(|v| Decimal6::from_str(v).unwrap())
+            .collect();
+        assert_eq!(Decimal6::try_sum(values.iter().copied()), Ok(values.iter().sum()));
+        assert_eq!(
+            Decimal6::try_product(values.iter().copied()),
+            Ok(values.iter().product())
+        );
+        assert_eq!(Decimal6::try_sum(core::iter::empty()), Ok(Decimal6::ZERO));
+        assert_eq!(Decimal6::try_product(core::iter::empty()), Ok(Decimal6::ONE));
+
+        // Overflow in the middle of the fold
+        let overflowing = [Decimal6::ONE, Decimal6::MAX, Decimal6::ONE, Decimal6::ZERO];
+        assert_eq!(
+            Decimal6::try_sum(overflowing.into_iter()),
+            Err(CustomDecimalError::Overflow)
+        );
+        let big = Decimal6::from(u64::MAX);
+        assert_eq!(
+            Decimal6::try_product([big, big, big, Decimal6::ZERO].into_iter()),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_from_integer_types() {
+        let forty_two = Decimal6::from_str("42.0").unwrap();
+        assert_eq!(Decimal6::from(42u8), forty_two);
+        assert_eq!(Decimal6::from(42u16), forty_two);
+        assert_eq!(Decimal6::from(42u32), forty_two);
+        assert_eq!(Decimal6::from(Uint64::new(42)), forty_two);
+        assert_eq!(Decimal6::try_from(42i64), Ok(forty_two));
+        assert_eq!(Decimal6::try_from(42i128), Ok(forty_two));
+        assert_eq!(Decimal6::try_from(0i64), Ok(Decimal6::ZERO));
+
+        assert!(matches!(
+            Decimal6::try_from(-1i64),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+        assert!(matches!(
+            Decimal6::try_from(i128::MIN),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+
+        // Scaling by 10^D overflows instead of panicking
+        assert_eq!(Decimal6::try_from(i128::MAX), Err(CustomDecimalError::Overflow));
+        assert_eq!(Decimal::<38>::try_from(i64::MAX), Err(CustomDecimalError::Overflow));
+        assert_eq!(Decimal::<38>::try_from(3i64), Ok(Decimal::<38>::from(3u64)));
+    }
+
+    #[test]
+    fn test_try_into_uint128() {
+        let five = Decimal6::from_str("5.0").unwrap();
+        assert!(five.is_integer());
+        assert_eq!(Uint128::try_from(five), Ok(Uint128::new(5)));
+        assert_eq!(Uint128::try_from(Decimal6::ZERO), Ok(Uint128::zero()));
+
+        let half = Decimal6::from_str("5.5").unwrap();
+        assert!(!half.is_integer());
+        assert!(matches!(
+            Uint128::try_from(half),
+            Err(CustomDecimalError::ConversionError(_))
+        ));
+        assert!(!Decimal6::raw(1).is_integer());
+        assert!(Decimal::<0>::from(7u64).is_integer());
+    }
+
+    #[test]
+    fn test_is_one() {
+        assert!(Decimal::<6>::ONE.is_one());
+        assert!(Decimal::<6>::from_str("1.000000").unwrap().is_one());
+        assert!(!Decimal::<6>::ZERO.is_one());
+        assert!(!Decimal::<6>::raw(1_000_001).is_one());
+        assert!(Decimal::<0>::ONE.is_one());
+    }
+
+    #[test]
+    fn test_signum_and_is_positive() {
+        assert_eq!(Decimal6::ZERO.signum(), Decimal6::ZERO);
+        assert_eq!(Decimal6::percent(50).signum(), Decimal6::ONE);
+        assert_eq!(Decimal6::MAX.signum(), Decimal6::ONE);
+        assert_eq!(Decimal::<0>::from(7u64).signum(), Decimal::<0>::ONE);
+
+        assert!(!Decimal6::ZERO.is_positive());
+        assert!(Decimal6::EPSILON.is_positive());
+        assert!(Decimal6::MAX.is_positive());
+
+        // Agrees with SignedDecimal for non-negative values
+        for value in [Decimal6::ZERO, Decimal6::percent(50), Decimal6::MAX] {
+            let signed = SignedDecimal::from(value);
+            assert_eq!(SignedDecimal::from(value.signum()), signed.signum());
+            assert_eq!(value.is_positive(), signed.is_positive());
+        }
+    }
+
+    #[test]
+    fn test_to_uint_round() {
+        let d = |s: &str| Decimal6::from_str(s).unwrap();
+        assert_eq!(d("2.5").to_uint_round(), Uint128::new(3));
+        assert_eq!(d("2.4").to_uint_round(), Uint128::new(2));
+        assert_eq!(d("2.500001").to_uint_round(), Uint128::new(3));
+        assert_eq!(d("2.499999").to_uint_round(), Uint128::new(2));
+        assert_eq!(d("0.5").to_uint_round(), Uint128::new(1));
+        assert_eq!(Decimal6::ZERO.to_uint_round(), Uint128::zero());
+        assert_eq!(d("7").to_uint_round(), Uint128::new(7));
+        assert_eq!(Decimal6::MAX.to_uint_round(), Decimal6::MAX.to_uint_floor());
+        assert_eq!(Decimal::<0>::MAX.to_uint_round(), Uint128::MAX);
+    }
+
+    #[test]
+    fn test_to_uint_round_bankers() {
+        let d = |s: &str| Decimal6::from_str(s).unwrap();
+        // Ties go to the even neighbour
+        assert_eq!(d("2.5").to_uint_round_bankers(), Uint128::new(2));
+        assert_eq!(d("3.5").to_uint_round_bankers(), Uint128::new(4));
+        assert_eq!(d("0.5").to_uint_round_bankers(), Uint128::zero());
+        assert_eq!(d("1.5").to_uint_round_bankers(), Uint128::new(2));
+        // Non-ties round to nearest like to_uint_round
+        assert_eq!(d("2.500001").to_uint_round_bankers(), Uint128::new(3));
+        assert_eq!(d("3.4").to_uint_round_bankers(), Uint128::new(3));
+        assert_eq!(d("3.6").to_uint_round_bankers(), Uint128::new(4));
+    }
+
+    #[test]
+    fn test_to_uint_floor_clamped() {
+        let d = Decimal::<6>::from_str("7.9").unwrap();
+        assert_eq!(d.to_uint_floor_clamped(Uint128::new(10)), Uint128::new(7));
+        assert_eq!(d.to_uint_floor_clamped(Uint128::new(7)), Uint128::new(7));
+        assert_eq!(d.to_uint_floor_clamped(Uint128::new(5)), Uint128::new(5));
+        assert_eq!(
+            Decimal::<6>::MAX.to_uint_floor_clamped(Uint128::new(1_000)),
+            Uint128::new(1_000)
+        );
+    }
+
+    #[test]
+    fn test_pow_family_at_overflow_boundary() {
+        // 2^108 fits in Decimal<6>, 2^109 does not
+        let two = Decimal::<6>::from_str("2.0").unwrap();
+        let last_ok = Decimal::<6>::from(1u128 << 108);
+
+        assert_eq!(two.pow(108), last_ok);
+        assert_eq!(two.checked_pow(108), Some(last_ok));
+        assert_eq!(two.saturating_pow(108), last_ok);
+        assert_eq!(two.try_pow(108), Ok(last_ok));
+
+        // Each member reports overflow differently
+        assert_eq!(two.checked_pow(109), None);
+        assert_eq!(two.saturating_pow(109), Decimal::<6>::MAX);
+        assert_eq!(two.try_pow(109), Err(CustomDecimalError::Overflow));
+
+        // try_pow propagates into a handler's StdResult with `?`
+        fn cube(base: Decimal6) -> cosmwasm_std::StdResult<Decimal6> {
+            Ok(base.try_pow(3)?)
+        }
+        assert_eq!(cube(two).unwrap(), Decimal6::from(8u64));
+        assert!(cube(Decimal6::MAX).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in pow")]
+    fn test_pow_overflow_panics() {
+        let _ = Decimal::<6>::from_str("2.0").unwrap().pow(109);
+    }
+
+    #[test]
+    fn test_full_mul() {
+        let two = Decimal::<6>::from_str("2.0").unwrap();
+        assert_eq!(Decimal::<6>::MAX.checked_mul(two), None);
+
+        let wide = Decimal::<6>::MAX.full_mul(two);
+        assert_eq!(wide.atomics(), Uint256::from(u128::MAX) * Uint256::from(2u128));
+        assert!(Decimal::<6>::try_from(wide).is_err());
+
+        // Narrowing after scaling back down recovers the original value
+        let back = wide / Decimal256Custom::from(two);
+        assert_eq!(Decimal::<6>::try_from(back).unwrap(), Decimal::<6>::MAX);
+
+        // Agrees with checked_mul whenever that succeeds, including truncation
+        let a = Decimal::<6>::from_str("1.234567").unwrap();
+        let b = Decimal::<6>::from_str("0.000003").unwrap();
+        assert_eq!(a.full_mul(b), Decimal256Custom::from(a.checked_mul(b).unwrap()));
+    }
+
+    #[test]
+    fn test_mul_floor_ceil() {
+        let d = Decimal::<6>::from_str("2.5").unwrap();
+        assert_eq!(d.mul_ceil(Uint128::new(3)), Uint128::new(8));
+        assert_eq!(d.mul_floor(Uint128::new(3)), Uint128::new(7));
+
+        // Exact products round identically
+        assert_eq!(d.mul_ceil(Uint128::new(4)), Uint128::new(10));
+        assert_eq!(d.mul_floor(Uint128::new(4)), Uint128::new(10));
+
+        // A single atomic unit still rounds up
+        let dust = Decimal::<6>::raw(1);
+        assert_eq!(dust.mul_floor(Uint128::new(1)), Uint128::zero());
+        assert_eq!(dust.mul_ceil(Uint128::new(1)), Uint128::one());
+
+        // Large amounts do not overflow the intermediate
+        assert_eq!(Decimal::<6>::ONE.mul_floor(Uint128::MAX), Uint128::MAX);
+    }
+
+    #[test]
+    fn test_mul_uint_floor_ceil() {
+        let half = Decimal::<6>::from_str("0.5").unwrap();
+        assert_eq!(half.mul_uint_ceil(3u128.into()), Uint128::new(2));
+        assert_eq!(half.mul_uint_floor(3u128.into()), Uint128::new(1));
+
+        // Exact products are unaffected
+        assert_eq!(half.mul_uint_ceil(4u128.into()), Uint128::new(2));
+        assert_eq!(half.mul_uint_floor(4u128.into()), Uint128::new(2));
+
+        let d = Decimal::<6>::from_str("1.000001").unwrap();
+        assert_eq!(d.mul_uint_ceil(Uint128::new(10)), Uint128::new(11));
+        assert_eq!(d.mul_uint_floor(Uint128::new(10)), Uint128::new(10));
+    }
+
+    #[test]
+    fn test_checked_mul_uint() {
+        let half = Decimal6::from_str("0.5").unwrap();
+        assert_eq!(half.checked_mul_uint(Uint128::new(3)), Some(half * Uint128::new(3)));
+        assert_eq!(half.checked_mul_uint_floor(Uint128::new(3)), Some(Uint128::new(1)));
+        assert_eq!(half.checked_mul_uint_ceil(Uint128::new(3)), Some(Uint128::new(2)));
+        assert_eq!(Decimal6::ONE.checked_mul_uint(Uint128::MAX), Some(Uint128::MAX));
+
+        assert_eq!(Decimal6::MAX.checked_mul_uint(Uint128::MAX), None);
+        assert_eq!(Decimal6::MAX.checked_mul_uint_floor(Uint128::MAX), None);
+        assert_eq!(Decimal6::MAX.checked_mul_uint_ceil(Uint128::MAX), None);
+    }
+
+    #[test]
+    fn test_dot() {
+        let prices = [
+            Decimal6::from_str("1.333333").unwrap(),
+            Decimal6::from_str("0.666667").unwrap(),
+            Decimal6::from_str("2.5").unwrap(),
+        ];
+        let quantities = [Uint128::new(7), Uint128::new(11), Uint128::new(3)];
+
+        // 9.333331 + 7.333337 + 7.5 = 24.166668
+        let naive: Uint128 = prices.iter().zip(quantities).map(|(p, q)| *p * q).sum();
+        assert_eq!(naive, Uint128::new(23));
+        assert_eq!(Decimal6::dot(&prices, &quantities), Some(Uint128::new(24)));
+
+        // Matches the per-element decimal sum when widened first
+        let reference: Decimal6 = prices
+            .iter()
+            .zip(quantities)
+            .map(|(p, q)| *p * Decimal6::from(q))
+            .sum();
+        assert_eq!(Decimal6::dot(&prices, &quantities), Some(reference.to_uint_floor()));
+
+        let weights = quantities.map(Decimal6::from);
+        assert_eq!(Decimal6::dot_decimal(&prices, &weights), Some(reference));
+        let halves = [Decimal6::from_str("0.5").unwrap(); 3];
+        let thirds = [Decimal6::from_str("0.333333").unwrap(); 3];
+        // Each term is 0.1666665, which truncates to 0.166666 on its own
+        assert_eq!(
+            Decimal6::dot_decimal(&halves, &thirds),
+            Some(Decimal6::from_str("0.499999").unwrap())
+        );
+        assert_eq!(
+            halves.iter().zip(thirds).map(|(a, b)| *a * b).sum::<Decimal6>(),
+            Decimal6::from_str("0.499998").unwrap()
+        );
+
+        assert_eq!(Decimal6::dot(&[], &[]), Some(Uint128::zero()));
+        assert_eq!(Decimal6::dot(&prices, &quantities[..2]), None);
+        assert_eq!(Decimal6::dot_decimal(&prices, &weights[..1]), None);
+        assert_eq!(Decimal6::dot(&[Decimal6::MAX], &[Uint128::MAX]), None);
+        assert_eq!(Decimal6::dot_decimal(&[Decimal6::MAX; 2], &[Decimal6::ONE; 2]), None);
+        // Intermediate sums may exceed Uint256 only for absurd inputs
+        assert_eq!(Decimal6::dot(&[Decimal6::MAX; 3], &[Uint128::MAX; 3]), None);
+    }
+
+    #[test]
+    fn test_div_floor_ceil() {
+        let d = Decimal::<6>::from_str("7.5").unwrap();
+        assert_eq!(d.div_floor(Uint128::new(2)), Uint128::new(3));
+        assert_eq!(d.div_ceil(Uint128::new(2)), Uint128::new(4));
+
+        let d = Decimal::<6>::from_str("9.0").unwrap();
+        assert_eq!(d.div_floor(Uint128::new(3)), Uint128::new(3));
+        assert_eq!(d.div_ceil(Uint128::new(3)), Uint128::new(3));
+
+        assert_eq!(Decimal::<6>::raw(1).div_ceil(Uint128::MAX), Uint128::one());
     }
-}
 
-/// Try to convert from Decimal256
-impl<const D: u32> TryFrom<Decimal256> for Decimal<D> {
-    type Error = CustomDecimalError;
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_div_floor_by_zero() {
+        let _ = Decimal::<6>::ONE.div_floor(Uint128::zero());
+    }
 
-    fn try_from(value: Decimal256) -> Result<Self, Self::Error> {
-        // Try to convert Decimal256 -> StdDecimal first
-        let decimal =
-            StdDecimal::try_from(value).map_err(|_| CustomDecimalError::ConversionError(
-                "Decimal256 value too large for Decimal".to_string(),
-            ))?;
-        Ok(decimal.into())
+    #[test]
+    fn test_decimal_conversion() {
+        let custom = Decimal::<6>::from_str("1.5").unwrap();
+        let decimal: StdDecimal = custom.into();
+        let back: Decimal::<6> = decimal.into();
+        assert_eq!(custom, back);
     }
-}
 
-// ========== Display & FromStr ==========
+    #[test]
+    fn test_widen() {
+        let d6 = Decimal::<6>::from_str("1.123456").unwrap();
+        let d9: Decimal<9> = d6.widen();
+        assert_eq!(d9.atomics(), 1_123_456_000);
 
-impl<const D: u32> fmt::Display for Decimal<D> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let fractional = Self::FRACTIONAL;
-        let integer = self.0.u128() / fractional;
-        let frac_part = self.0.u128() % fractional;
+        let d18: Decimal<18> = d6.widen();
+        assert_eq!(d18.atomics(), 1_123_456_000_000_000_000);
+        assert_eq!(d18.to_precision::<6>(), d6);
 
-        if frac_part == 0 {
-            write!(f, "{}", integer)
-        } else {
-            // Trim trailing zeros
-            let frac_str = format!("{:0>width$}", frac_part, width = D as usize);
-            let trimmed = frac_str.trim_end_matches('0');
-            write!(f, "{}.{}", integer, trimmed)
-        }
+        let same: Decimal<6> = d6.widen();
+        assert_eq!(same, d6);
     }
-}
 
-impl<const D: u32> fmt::Debug for Decimal<D> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Decimal<{}>({})", D, self)
+    #[test]
+    #[should_panic(expected = "precision conversion overflow")]
+    fn test_widen_overflow() {
+        let _: Decimal<18> = Decimal::<6>::MAX.widen();
     }
-}
 
-impl<const D: u32> FromStr for Decimal<D> {
-    type Err = CustomDecimalError;
+    #[test]
+    fn test_narrow() {
+        let d18 = Decimal::<18>::from_str("1.123456789012345678").unwrap();
+        let d6: Decimal<6> = d18.narrow();
+        assert_eq!(d6, Decimal::<6>::from_str("1.123456").unwrap());
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split('.').collect();
+        let d9: Decimal<9> = d18.narrow();
+        assert_eq!(d9.atomics(), 1_123_456_789);
 
-        match parts.len() {
-            1 => {
-                // Integer only
-                let integer = parts[0]
-                    .parse::<u128>()
-                    .map_err(|_| CustomDecimalError::ParseError(format!("Invalid integer: {}", parts[0])))?;
+        // MAX narrows without overflow
+        let max: Decimal<6> = Decimal::<18>::MAX.narrow();
+        assert_eq!(max.atomics(), u128::MAX / 1_000_000_000_000);
 
-                Ok(Self(Uint128::from(integer * Self::FRACTIONAL)))
-            }
-            2 => {
-                // Integer and fractional parts
-                let integer = parts[0]
-                    .parse::<u128>()
-                    .map_err(|_| CustomDecimalError::ParseError(format!("Invalid integer: {}", parts[0])))?;
+        // widen then narrow is lossless
+        let back: Decimal<6> = d6.widen::<18>().narrow();
+        assert_eq!(back, d6);
+    }
 
-                let fractional_str = parts[1];
-                if fractional_str.len() > D as usize {
-                    return Err(CustomDecimalError::ParseError(format!(
-                        "Too many decimal places: {} (max {})",
-                        fractional_str.len(),
-                        D
-                    )));
-                }
+    #[test]
+    fn test_add_sub_atomics() {
+        let d = Decimal::<6>::from_str("1.5").unwrap();
+        assert_eq!(d.add_atomics(1), Some(Decimal::<6>::raw(1_500_001)));
+        assert_eq!(d.sub_atomics(500_000), Some(Decimal::<6>::ONE));
+        assert_eq!(d.add_atomics(0), Some(d));
+
+        // Overflow/underflow boundaries
+        assert_eq!(Decimal::<6>::raw(u128::MAX - 1).add_atomics(1), Some(Decimal::<6>::MAX));
+        assert_eq!(Decimal::<6>::MAX.add_atomics(1), None);
+        assert_eq!(Decimal::<6>::raw(1).sub_atomics(1), Some(Decimal::<6>::ZERO));
+        assert_eq!(Decimal::<6>::ZERO.sub_atomics(1), None);
+    }
 
-                let fractional = fractional_str
-                    .parse::<u128>()
-                    .map_err(|_| CustomDecimalError::ParseError(format!("Invalid fractional: {}", fractional_str)))?;
+    #[test]
+    fn test_checked_div_with_rounding() {
+        let one = Decimal::<6>::ONE;
+        let two = Decimal::<6>::from(2u64);
+        let three = Decimal::<6>::from(3u64);
+
+        // 1/3 = 0.333333...: only Ceil rounds up
+        let third = Decimal::<6>::raw(333_333);
+        assert_eq!(one.checked_div_with(three, RoundingMode::Truncate), Some(third));
+        assert_eq!(one.checked_div_with(three, RoundingMode::Floor), Some(third));
+        assert_eq!(one.checked_div_with(three, RoundingMode::HalfEven), Some(third));
+        assert_eq!(one.checked_div_with(three, RoundingMode::HalfUp), Some(third));
+        assert_eq!(
+            one.checked_div_with(three, RoundingMode::Ceil),
+            Some(Decimal::<6>::raw(333_334))
+        );
 
-                // Scale to D decimals
-                let scaled_fractional =
-                    fractional * pow10(D - fractional_str.len() as u32);
+        // 2/3 = 0.666666...: nearest modes round up
+        assert_eq!(
+            two.checked_div_with(three, RoundingMode::HalfUp),
+            Some(Decimal::<6>::raw(666_667))
+        );
+        assert_eq!(
+            two.checked_div_with(three, RoundingMode::HalfEven),
+            Some(Decimal::<6>::raw(666_667))
+        );
+        assert_eq!(
+            two.checked_div_with(three, RoundingMode::Truncate),
+            two.checked_div(three)
+        );
 
-                let total = integer
-                    .checked_mul(Self::FRACTIONAL)
-                    .and_then(|i| i.checked_add(scaled_fractional))
-                    .ok_or(CustomDecimalError::Overflow)?;
+        // Tie at the last place: 0.000001 / 2 = 0.0000005
+        let dust = Decimal::<6>::raw(1);
+        assert_eq!(dust.checked_div_with(two, RoundingMode::HalfUp), Some(dust));
+        assert_eq!(dust.checked_div_with(two, RoundingMode::HalfEven), Some(Decimal::<6>::ZERO));
 
-                Ok(Self(Uint128::from(total)))
-            }
-            _ => Err(CustomDecimalError::ParseError(format!(
-                "Invalid decimal format: {}",
-                s
-            ))),
-        }
+        assert_eq!(one.checked_div_with(Decimal::<6>::ZERO, RoundingMode::HalfUp), None);
+        assert_eq!(Decimal::<6>::MAX.checked_div_with(Decimal::<6>::raw(1), RoundingMode::Ceil), None);
     }
-}
 
-// ========== Iterator Traits ==========
+    #[test]
+    fn test_to_precision_with_rounding() {
+        let d9 = Decimal::<9>::from_str("1.1234565").unwrap();
+        assert_eq!(
+            d9.to_precision_with::<6>(RoundingMode::Truncate),
+            Decimal::<6>::from_str("1.123456").unwrap()
+        );
+        assert_eq!(
+            d9.to_precision_with::<6>(RoundingMode::HalfUp),
+            Decimal::<6>::from_str("1.123457").unwrap()
+        );
+        // Tie with even last digit stays
+        assert_eq!(
+            d9.to_precision_with::<6>(RoundingMode::HalfEven),
+            Decimal::<6>::from_str("1.123456").unwrap()
+        );
+        let d9 = Decimal::<9>::from_str("1.1234575").unwrap();
+        assert_eq!(
+            d9.to_precision_with::<6>(RoundingMode::HalfEven),
+            Decimal::<6>::from_str("1.123458").unwrap()
+        );
+        assert_eq!(
+            Decimal::<9>::raw(1).to_precision_with::<6>(RoundingMode::Ceil),
+            Decimal::<6>::raw(1)
+        );
 
-impl<const D: u32> Sum for Decimal<D> {
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Self::ZERO, |acc, x| acc + x)
+        // Scaling up ignores the mode
+        let d6 = Decimal::<6>::from_str("1.5").unwrap();
+        assert_eq!(d6.to_precision_with::<9>(RoundingMode::Ceil), d6.to_precision::<9>());
+
+        // Rounding MAX up does not overflow
+        let max: Decimal<6> = Decimal::<9>::MAX.to_precision_with(RoundingMode::Ceil);
+        assert_eq!(max.atomics(), u128::MAX / 1000 + 1);
     }
-}
 
-impl<'a, const D: u32> Sum<&'a Decimal<D>> for Decimal<D> {
-    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-        iter.fold(Self::ZERO, |acc, x| acc + x)
+    #[test]
+    fn test_raw_256_intermediates() {
+        let a = Decimal::<6>::from_str("1.000001").unwrap();
+        let fractional = Uint256::from(Decimal::<6>::FRACTIONAL);
+
+        // mul_raw_256 narrowed by FRACTIONAL matches checked_mul
+        let wide = a.mul_raw_256(a);
+        assert_eq!(wide, Uint256::from(1_000_002_000_001u128));
+        assert_eq!(Uint256::from(a.checked_mul(a).unwrap().0), wide / fractional);
+
+        // 0.000001 * 0.000001 * 1_000_000 = 0.000001
+        let dust = Decimal::<6>::raw(1);
+        let million = Decimal::<6>::from(1_000_000u128);
+
+        // Narrowing each step truncates the intermediate to zero
+        assert_eq!(dust * dust * million, Decimal::<6>::ZERO);
+
+        // Narrowing once at the end keeps the exact result
+        let chained = dust.mul_raw_256(dust) * Uint256::from(million.0) / (fractional * fractional);
+        assert_eq!(chained, Uint256::from(1u128));
+
+        // div_raw_256 keeps D extra places: (1 / 3) * 3
+        let one_third = Decimal::<6>::ONE.div_raw_256(Decimal::<6>::raw(3_000_000)).unwrap();
+        assert_eq!(one_third, Uint256::from(333_333_333_333u128));
+        let three = Decimal::<6>::raw(3_000_000);
+        let stepwise = (Decimal::<6>::ONE / three) * three;
+        let chained = one_third * Uint256::from(three.0) / fractional;
+        // 0.999999 stepwise vs 0.999999999999 (at 12 places) chained
+        assert_eq!(stepwise.atomics(), 999_999);
+        assert_eq!(chained, Uint256::from(999_999_999_999u128));
+
+        assert_eq!(Decimal::<6>::ONE.div_raw_256(Decimal::<6>::ZERO), None);
     }
-}
 
-impl<const D: u32> Product for Decimal<D> {
-    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Self::ONE, |acc, x| acc * x)
+    #[test]
+    fn test_compare_with_std_decimal() {
+        let d6 = Decimal6::from_str("1.5").unwrap();
+        let std = StdDecimal::from_str("1.5").unwrap();
+        assert_ne!(d6.atomics(), std.atomics().u128());
+        assert!(d6 == std);
+        assert!(std == d6);
+
+        // Digits beyond D still count in the comparison
+        let finer = StdDecimal::from_str("1.5000001").unwrap();
+        assert!(d6 != finer);
+        assert!(d6 < finer);
+        assert!(finer > d6);
+        assert!(Decimal6::from_str("1.500001").unwrap() > finer);
+
+        // Higher precision than StdDecimal
+        let d20 = Decimal::<20>::from_str("1.50000000000000000001").unwrap();
+        assert!(d20 > std);
+        assert!(std < d20);
+        assert!(Decimal::<20>::from_str("1.5").unwrap() == std);
+
+        // Extremes don't overflow
+        assert!(Decimal6::MAX > StdDecimal::MAX);
+        assert!(Decimal::<38>::MAX < StdDecimal::MAX);
+        assert!(Decimal6::ZERO == StdDecimal::zero());
     }
-}
 
-impl<'a, const D: u32> Product<&'a Decimal<D>> for Decimal<D> {
-    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-        iter.fold(Self::ONE, |acc, x| acc * x)
+    #[test]
+    fn test_compare_with_uint128() {
+        let hundred = Uint128::new(100);
+        assert!(Decimal6::from_str("100.0").unwrap() == hundred);
+        assert!(hundred == Decimal6::from_str("100").unwrap());
+
+        let below = Decimal6::from_str("99.9").unwrap();
+        assert!(below < hundred);
+        assert!(hundred > below);
+        assert!(below != hundred);
+
+        // The fractional part counts, right at the boundary too
+        let above = Decimal6::from_str("100.5").unwrap();
+        assert!(above > hundred);
+        assert!(hundred < above);
+        assert!(Decimal6::from(100u64) + Decimal6::EPSILON > hundred);
+        assert!(Decimal6::from(100u64) - Decimal6::EPSILON < hundred);
+        assert!(Decimal6::from(100u64) >= hundred && Decimal6::from(100u64) <= hundred);
+
+        // Integers beyond MAX don't overflow the comparison
+        assert!(Decimal6::MAX < Uint128::MAX);
+        assert!(Uint128::MAX > Decimal6::MAX);
+        assert!(Decimal6::ZERO == Uint128::zero());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_try_arithmetic() {
+        let half = Decimal6::percent(50);
+        assert_eq!(Decimal6::ONE.try_add(half), Ok(Decimal6::percent(150)));
+        assert_eq!(Decimal6::ONE.try_sub(half), Ok(half));
+        assert_eq!(Decimal6::ONE.try_mul(half), Ok(half));
+        assert_eq!(Decimal6::ONE.try_div(half), Ok(Decimal6::from(2u64)));
+
+        assert_eq!(Decimal6::ZERO.try_sub(Decimal6::ONE), Err(CustomDecimalError::Underflow));
+        assert_eq!(Decimal6::ONE.try_div(Decimal6::ZERO), Err(CustomDecimalError::DivisionByZero));
+        assert_eq!(Decimal6::MAX.try_add(Decimal6::EPSILON), Err(CustomDecimalError::Overflow));
+        assert_eq!(Decimal6::MAX.try_mul(Decimal6::from(2u64)), Err(CustomDecimalError::Overflow));
+        assert_eq!(Decimal6::MAX.try_div(half), Err(CustomDecimalError::Overflow));
+
+        // Errors convert into StdError with `?`
+        fn handler() -> Result<Decimal6, cosmwasm_std::StdError> {
+            Ok(Decimal6::ZERO.try_sub(Decimal6::ONE)?)
+        }
+        assert!(handler().unwrap_err().to_string().contains("underflow"));
+    }
 
     #[test]
-    fn test_constants() {
-        assert_eq!(Decimal::<6>::ZERO.0, Uint128::zero());
-        assert_eq!(Decimal::<6>::ONE.0, Uint128::new(1_000_000));
-        assert_eq!(Decimal::<6>::DECIMAL_PLACES, 6);
-        assert_eq!(Decimal::<6>::FRACTIONAL, 1_000_000);
+    fn test_compound() {
+        let principal = Decimal6::from(1000u64);
+        let rate = Decimal6::percent(5);
+        assert_eq!(principal.compound(rate, 3), Decimal6::from_str("1157.625").unwrap());
+        assert_eq!(
+            principal.compound(rate, 3),
+            principal * (Decimal6::ONE + rate).pow(3)
+        );
+        assert_eq!(principal.compound(rate, 0), principal);
+        assert_eq!(principal.compound(Decimal6::ZERO, 1_000_000), principal);
 
-        assert_eq!(Decimal::<9>::ONE.0, Uint128::new(1_000_000_000));
-        assert_eq!(Decimal::<9>::DECIMAL_PLACES, 9);
+        assert_eq!(principal.checked_compound(rate, 100_000), Err(CustomDecimalError::Overflow));
+        // The factor fits but the result does not
+        assert_eq!(
+            Decimal6::MAX.checked_compound(rate, 1),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
 
-        assert_eq!(Decimal::<18>::ONE.0, Uint128::new(1_000_000_000_000_000_000));
-        assert_eq!(Decimal::<18>::DECIMAL_PLACES, 18);
+    #[test]
+    #[should_panic(expected = "overflow in compound")]
+    fn test_compound_overflow_panics() {
+        let _ = Decimal6::ONE.compound(Decimal6::ONE, 200);
     }
 
     #[test]
-    fn test_raw() {
-        let d = Decimal::<6>::raw(1_500_000);
-        assert_eq!(d.0, Uint128::new(1_500_000));
+    fn test_apply_fee() {
+        let amount = Decimal6::from(100u64);
+        let rate = Decimal6::percent(3);
+        assert_eq!(amount.apply_fee(rate), Decimal6::from(97u64));
+        assert_eq!(amount.fee_amount(rate), Decimal6::from(3u64));
+
+        // Inexact fees round down once, and net + fee always recovers the amount
+        let rate = Decimal6::bps(33);
+        for amount in [Decimal6::raw(1), Decimal6::raw(999_999), Decimal6::from_ratio(10u128, 3u128), Decimal6::MAX] {
+            assert_eq!(amount.apply_fee(rate) + amount.fee_amount(rate), amount);
+        }
+        assert_eq!(Decimal6::raw(999).fee_amount(Decimal6::percent(50)), Decimal6::raw(499));
+        assert_eq!(Decimal6::raw(999).apply_fee(Decimal6::percent(50)), Decimal6::raw(500));
+
+        assert_eq!(amount.apply_fee(Decimal6::ZERO), amount);
+        assert_eq!(amount.apply_fee(Decimal6::ONE), Decimal6::ZERO);
+        assert_eq!(
+            amount.checked_apply_fee(Decimal6::ONE + Decimal6::EPSILON),
+            Err(CustomDecimalError::RangeExceeded)
+        );
     }
 
     #[test]
-    fn test_from_atomics() {
-        let d = Decimal::<6>::from_atomics(15u128, 1).unwrap();
-        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+    #[should_panic(expected = "fee rate must not exceed ONE")]
+    fn test_fee_rate_above_one_panics() {
+        let _ = Decimal6::ONE.fee_amount(Decimal6::percent(101));
+    }
 
-        let d = Decimal::<6>::from_atomics(1_500_000u128, 6).unwrap();
-        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+    #[test]
+    fn test_multiply_ratio_fused_vs_chained() {
+        let one = Decimal6::ONE;
+        let chained = one / Uint128::new(3) * Decimal6::from(3u64);
+        assert_eq!(chained, Decimal6::raw(999_999));
+        assert_eq!(one.multiply_ratio(3u128, 3u128), one);
+
+        // 1.000001 * 2/3 = 0.666667333..
+        let d = Decimal6::from_str("1.000001").unwrap();
+        let chained = d / Uint128::new(3) * Decimal6::from(2u64);
+        assert_eq!(chained, Decimal6::raw(666_666));
+        assert_eq!(d.multiply_ratio(2u128, 3u128), Decimal6::raw(666_667));
+
+        // The intermediate product may exceed Uint128
+        assert_eq!(
+            Decimal6::MAX.checked_multiply_ratio(u128::MAX, u128::MAX),
+            Ok(Decimal6::MAX)
+        );
+    }
 
-        let d = Decimal::<6>::from_atomics(1_500_000_000u128, 9).unwrap();
-        assert_eq!(d.0, Uint128::new(1_500_000)); // 1.5
+    #[test]
+    fn test_checked_multiply_ratio_errors() {
+        assert_eq!(
+            Decimal6::ONE.checked_multiply_ratio(1u128, 0u128),
+            Err(CustomDecimalError::DivisionByZero)
+        );
+        assert_eq!(
+            Decimal6::MAX.checked_multiply_ratio(2u128, 1u128),
+            Err(CustomDecimalError::Overflow)
+        );
     }
 
     #[test]
-    fn test_percent() {
-        assert_eq!(Decimal::<6>::percent(0), Decimal::<6>::ZERO);
-        assert_eq!(Decimal::<6>::percent(50), Decimal::<6>::raw(500_000));
-        assert_eq!(Decimal::<6>::percent(100), Decimal::<6>::ONE);
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn test_multiply_ratio_zero_denominator_panics() {
+        let _ = Decimal6::ONE.multiply_ratio(1u128, 0u128);
     }
 
     #[test]
-    fn test_permille() {
-        assert_eq!(Decimal::<6>::permille(0), Decimal::<6>::ZERO);
-        assert_eq!(Decimal::<6>::permille(125), Decimal::<6>::raw(125_000));
-        assert_eq!(Decimal::<6>::permille(1000), Decimal::<6>::ONE);
+    fn test_div_rem_invariant() {
+        let cases = [
+            ("10.5", "3.0"),
+            ("7.25", "0.5"),
+            ("0.999999", "0.3"),
+            ("2.0", "7.0"),
+            ("123.456789", "0.000007"),
+        ];
+        for (a, b) in cases {
+            let a = Decimal6::from_str(a).unwrap();
+            let b = Decimal6::from_str(b).unwrap();
+            let (q, r) = a.div_rem(b);
+            assert_eq!(q, q.floor());
+            assert!(r < b);
+            assert_eq!(r, a % b);
+            assert_eq!(q * b + r, a);
+        }
+
+        assert_eq!(Decimal6::ONE.checked_div_rem(Decimal6::ZERO), None);
+        // Quotient of MAX / 0.000001 cannot be represented
+        assert_eq!(Decimal6::MAX.checked_div_rem(Decimal6::raw(1)), None);
     }
 
     #[test]
-    fn test_bps() {
-        assert_eq!(Decimal::<6>::bps(0), Decimal::<6>::ZERO);
-        assert_eq!(Decimal::<6>::bps(50), Decimal::<6>::raw(5_000));
-        assert_eq!(Decimal::<6>::bps(10000), Decimal::<6>::ONE);
+    #[should_panic(expected = "Division by zero")]
+    fn test_div_rem_by_zero_panics() {
+        let _ = Decimal6::ONE.div_rem(Decimal6::ZERO);
     }
 
     #[test]
-    fn test_from_ratio() {
-        let d = Decimal::<6>::from_ratio(3u128, 2u128);
-        assert_eq!(d, Decimal::<6>::from_str("1.5").unwrap());
+    fn test_euclid() {
+        for (a, b) in [("10.5", "3.0"), ("7.25", "0.5"), ("0.2", "0.7")] {
+            let a = Decimal6::from_str(a).unwrap();
+            let b = Decimal6::from_str(b).unwrap();
+            let q = a.div_euclid(b);
+            let r = a.rem_euclid(b);
+            assert_eq!(q, q.floor());
+            assert_eq!(r, a % b);
+            assert_eq!(q * b + r, a);
+        }
 
-        let d = Decimal::<6>::from_ratio(1u128, 3u128);
-        assert_eq!(d.0, Uint128::new(333_333)); // 0.333333
+        assert_eq!(Decimal6::ONE.checked_div_euclid(Decimal6::ZERO), None);
+        assert_eq!(Decimal6::ONE.checked_rem_euclid(Decimal6::ZERO), None);
     }
 
     #[test]
-    fn test_from_str() {
+    fn test_wrapping_operations() {
+        // MAX + 1.0 wraps to 1.0 - 1 atomic
+        assert_eq!(Decimal6::MAX.wrapping_add(Decimal6::ONE), Decimal6::raw(999_999));
+        assert_eq!(Decimal6::ZERO.wrapping_sub(Decimal6::raw(1)), Decimal6::MAX);
+
+        // 2^64 atomics times the integer 2^64 is exactly 2^128 atomics
+        let big = Decimal6::raw(1u128 << 64);
+        assert_eq!(big.wrapping_mul(Decimal6::from(1u128 << 64)), Decimal6::ZERO);
+        // 2 * (2^128 - 1) wraps to 2^128 - 2
+        let two = Decimal6::from(2u64);
+        assert_eq!(Decimal6::MAX.wrapping_mul(two), Decimal6::raw(u128::MAX - 1));
+
+        // Without overflow, wrapping matches checked
+        let a = Decimal6::from_str("12.345678").unwrap();
+        let b = Decimal6::from_str("0.5").unwrap();
+        assert_eq!(a.wrapping_add(b), a.checked_add(b).unwrap());
+        assert_eq!(a.wrapping_sub(b), a.checked_sub(b).unwrap());
+        assert_eq!(a.wrapping_mul(b), a.checked_mul(b).unwrap());
+    }
+
+    #[test]
+    fn test_from_std_decimal_rounded() {
+        let std = StdDecimal::from_str("1.1234565").unwrap();
+        assert_eq!(Decimal6::from(std), Decimal6::from_str("1.123456").unwrap());
         assert_eq!(
-            Decimal::<6>::from_str("1.5").unwrap(),
-            Decimal::<6>::raw(1_500_000)
+            Decimal6::from_std_decimal_rounded(std, RoundingMode::HalfUp),
+            Decimal6::from_str("1.123457").unwrap()
         );
+        // 6 is even, so the tie stays put
         assert_eq!(
-            Decimal::<6>::from_str("123").unwrap(),
-            Decimal::<6>::raw(123_000_000)
+            Decimal6::from_std_decimal_rounded(std, RoundingMode::HalfEven),
+            Decimal6::from_str("1.123456").unwrap()
         );
         assert_eq!(
-            Decimal::<6>::from_str("0.123456").unwrap(),
-            Decimal::<6>::raw(123_456)
+            Decimal6::from_std_decimal_rounded(std, RoundingMode::Truncate),
+            Decimal6::from(std)
         );
-    }
 
-    #[test]
-    fn test_display() {
-        assert_eq!(Decimal::<6>::raw(1_500_000).to_string(), "1.5");
-        assert_eq!(Decimal::<6>::raw(1_000_000).to_string(), "1");
-        assert_eq!(Decimal::<6>::raw(123_456).to_string(), "0.123456");
-        assert_eq!(Decimal::<6>::raw(100_000).to_string(), "0.1");
+        // Exact values are unaffected by the mode; scaling up is lossless
+        let exact = StdDecimal::from_str("2.5").unwrap();
+        assert_eq!(
+            Decimal6::from_std_decimal_rounded(exact, RoundingMode::Ceil),
+            Decimal6::from_str("2.5").unwrap()
+        );
+        assert_eq!(
+            Decimal::<20>::from_std_decimal_rounded(std, RoundingMode::Ceil),
+            Decimal::<20>::from(std)
+        );
+        assert_eq!(
+            Decimal6::from_std_decimal_rounded(StdDecimal::MAX, RoundingMode::Ceil),
+            Decimal6::raw(u128::MAX / 1_000_000_000_000 + 1)
+        );
     }
 
     #[test]
-    fn test_floor_ceil() {
-        let d = Decimal::<6>::from_str("1.7").unwrap();
-        assert_eq!(d.floor(), Decimal::<6>::from_str("1.0").unwrap());
-        assert_eq!(d.ceil(), Decimal::<6>::from_str("2.0").unwrap());
+    fn test_try_from_std_scale_up() {
+        let d20 = Decimal::<20>::try_from_std_decimal(StdDecimal::from_str("1.5").unwrap()).unwrap();
+        assert_eq!(d20.atomics(), 150_000_000_000_000_000_000);
 
-        let d = Decimal::<6>::from_str("2.0").unwrap();
-        assert_eq!(d.floor(), d);
-        assert_eq!(d.ceil(), d);
+        let large = StdDecimal::MAX;
+        assert_eq!(
+            Decimal::<20>::try_from_std_decimal(large),
+            Err(CustomDecimalError::Overflow)
+        );
     }
 
     #[test]
-    fn test_sqrt() {
-        let d = Decimal::<6>::from_str("4.0").unwrap();
-        assert_eq!(d.sqrt(), Decimal::<6>::from_str("2.0").unwrap());
+    fn test_try_std_decimal_boundaries() {
+        // Largest StdDecimal that still fits in Decimal<20> after scaling by 100
+        let limit = StdDecimal::new(Uint128::new(u128::MAX / 100));
+        let d20 = Decimal::<20>::try_from_std_decimal(limit).unwrap();
+        assert_eq!(d20.atomics(), u128::MAX / 100 * 100);
+        assert_eq!(d20.try_to_std_decimal(), Ok(limit));
+
+        let over = StdDecimal::new(Uint128::new(u128::MAX / 100 + 1));
+        assert_eq!(
+            Decimal::<20>::try_from_std_decimal(over),
+            Err(CustomDecimalError::Overflow)
+        );
 
-        let d = Decimal::<6>::from_str("9.0").unwrap();
-        assert_eq!(d.sqrt(), Decimal::<6>::from_str("3.0").unwrap());
+        // Scaling Decimal6 up to 18 decimals overflows above Uint128::MAX / 10^12
+        let fits = Decimal6::raw(u128::MAX / 1_000_000_000_000);
+        assert!(fits.try_to_std_decimal().is_ok());
+        assert_eq!(
+            Decimal6::raw(u128::MAX / 1_000_000_000_000 + 1).try_to_std_decimal(),
+            Err(CustomDecimalError::Overflow)
+        );
+        assert_eq!(
+            Decimal6::from_str("1.5").unwrap().try_to_std_decimal(),
+            Ok(StdDecimal::from_str("1.5").unwrap())
+        );
     }
 
     #[test]
-    fn test_pow() {
-        let d = Decimal::<6>::from_str("2.0").unwrap();
-        assert_eq!(d.pow(0), Decimal::<6>::ONE);
-        assert_eq!(d.pow(1), d);
-        assert_eq!(d.pow(3), Decimal::<6>::from_str("8.0").unwrap());
+    #[should_panic(expected = "StdDecimal conversion overflow")]
+    fn test_to_std_overflow_panics() {
+        let _: StdDecimal = Decimal6::MAX.into();
     }
 
     #[test]
-    fn test_decimal_conversion() {
-        let custom = Decimal::<6>::from_str("1.5").unwrap();
-        let decimal: StdDecimal = custom.into();
-        let back: Decimal::<6> = decimal.into();
-        assert_eq!(custom, back);
+    #[should_panic(expected = "StdDecimal conversion overflow")]
+    fn test_from_std_scale_up_overflow_panics() {
+        let _: Decimal<20> = StdDecimal::MAX.into();
     }
 
     #[test]
@@ -853,6 +4679,70 @@ mod tests {
         assert_eq!(d6_back, d6);
     }
 
+    #[test]
+    fn test_convert_slice() {
+        let d6 = [Decimal6::ONE, Decimal6::from_str("1.234567").unwrap(), Decimal6::ZERO];
+        let d18: Vec<Decimal18> = Decimal6::convert_slice(&d6);
+        assert_eq!(d18, d6.iter().map(|d| d.to_precision()).collect::<Vec<Decimal18>>());
+
+        // Down and back up again truncates like to_precision
+        let d2: Vec<Decimal<2>> = Decimal18::convert_slice(&d18);
+        assert_eq!(d2[1], Decimal::<2>::from_str("1.23").unwrap());
+        assert_eq!(Decimal6::convert_slice::<6>(&d6), d6);
+        assert!(Decimal6::convert_slice::<18>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_try_convert_slice_reports_index() {
+        let values = [Decimal6::ONE, Decimal6::from(1_000u64), Decimal6::MAX, Decimal6::MAX];
+        assert_eq!(
+            Decimal6::try_convert_slice::<18>(&values),
+            Err(CustomDecimalError::SliceConversionOverflow {
+                index: 2,
+                from_decimals: 6,
+                to_decimals: 18,
+            })
+        );
+        assert!(Decimal6::try_convert_slice::<9>(&values[..2]).is_ok());
+    }
+
+    #[test]
+    fn test_scale_between() {
+        assert_eq!(scale_between::<6, 9>(), (1_000, true));
+        assert_eq!(scale_between::<9, 6>(), (1_000, false));
+        assert_eq!(scale_between::<6, 6>(), (1, true));
+        assert_eq!(scale_between::<0, 38>(), (pow10(38), true));
+        assert_eq!(scale_between::<18, 0>(), (Decimal18::FRACTIONAL, false));
+
+        // Usable in const contexts
+        const FACTOR: (u128, bool) = scale_between::<6, 18>();
+        assert_eq!(FACTOR, (SCALE_FACTOR, true));
+    }
+
+    #[test]
+    fn test_checked_to_precision_overflow() {
+        // Largest Decimal6 that still fits after scaling by 10^12
+        let limit = u128::MAX / 1_000_000_000_000;
+        let fits = Decimal::<6>::raw(limit).checked_to_precision::<18>().unwrap();
+        assert_eq!(fits.atomics(), limit * 1_000_000_000_000);
+
+        let err = Decimal::<6>::raw(limit + 1)
+            .checked_to_precision::<18>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CustomDecimalError::PrecisionConversionOverflow {
+                from_decimals: 6,
+                to_decimals: 18,
+            }
+        );
+        assert_eq!(Decimal::<6>::MAX.try_to_precision::<18>(), None);
+
+        // Scaling down never fails
+        let d6: Decimal<6> = Decimal::<18>::MAX.checked_to_precision().unwrap();
+        assert_eq!(d6.atomics(), u128::MAX / 1_000_000_000_000);
+    }
+
     #[test]
     fn test_precision_conversion_with_precision_loss() {
         let d9 = Decimal::<9>::from_str("1.123456789").unwrap();
@@ -862,7 +4752,7 @@ mod tests {
 
     #[test]
     fn test_sum() {
-        let values = vec![
+        let values = [
             Decimal::<6>::from_str("1.0").unwrap(),
             Decimal::<6>::from_str("2.0").unwrap(),
             Decimal::<6>::from_str("3.0").unwrap(),
@@ -873,7 +4763,7 @@ mod tests {
 
     #[test]
     fn test_product() {
-        let values = vec![
+        let values = [
             Decimal::<6>::from_str("2.0").unwrap(),
             Decimal::<6>::from_str("3.0").unwrap(),
         ];
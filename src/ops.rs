@@ -1,20 +1,20 @@
-use crate::Decimal;
+use crate::{Decimal, Decimal256Custom, SignedDecimal};
 use cosmwasm_std::{Uint128, Uint256};
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
 // ========== Macros to reduce boilerplate ==========
 
 /// Macro to implement binary operations for all 4 combinations:
-/// - Decimal<D> op Decimal<D>
-/// - Decimal<D> op &Decimal<D>
-/// - &Decimal<D> op Decimal<D>
-/// - &Decimal<D> op &Decimal<D>
+/// - T<D> op T<D>
+/// - T<D> op &T<D>
+/// - &T<D> op T<D>
+/// - &T<D> op &T<D>
 macro_rules! impl_binary_op {
-    ($trait:ident, $method:ident, $impl_fn:ident) => {
-        // Decimal<D> op Decimal<D>
-        impl<const D: u32> $trait for Decimal<D> {
+    ($ty:ident, $trait:ident, $method:ident, $impl_fn:ident) => {
+        // T<D> op T<D>
+        impl<const D: u32> $trait for $ty<D> {
             type Output = Self;
 
             fn $method(self, rhs: Self) -> Self::Output {
@@ -22,29 +22,29 @@ macro_rules! impl_binary_op {
             }
         }
 
-        // &Decimal<D> op Decimal<D>
-        impl<const D: u32> $trait<Decimal<D>> for &Decimal<D> {
-            type Output = Decimal<D>;
+        // &T<D> op T<D>
+        impl<const D: u32> $trait<$ty<D>> for &$ty<D> {
+            type Output = $ty<D>;
 
-            fn $method(self, rhs: Decimal<D>) -> Self::Output {
+            fn $method(self, rhs: $ty<D>) -> Self::Output {
                 $impl_fn(*self, rhs)
             }
         }
 
-        // Decimal<D> op &Decimal<D>
-        impl<const D: u32> $trait<&Decimal<D>> for Decimal<D> {
-            type Output = Decimal<D>;
+        // T<D> op &T<D>
+        impl<const D: u32> $trait<&$ty<D>> for $ty<D> {
+            type Output = $ty<D>;
 
-            fn $method(self, rhs: &Decimal<D>) -> Self::Output {
+            fn $method(self, rhs: &$ty<D>) -> Self::Output {
                 $impl_fn(self, *rhs)
             }
         }
 
-        // &Decimal<D> op &Decimal<D>
-        impl<const D: u32> $trait<&Decimal<D>> for &Decimal<D> {
-            type Output = Decimal<D>;
+        // &T<D> op &T<D>
+        impl<const D: u32> $trait<&$ty<D>> for &$ty<D> {
+            type Output = $ty<D>;
 
-            fn $method(self, rhs: &Decimal<D>) -> Self::Output {
+            fn $method(self, rhs: &$ty<D>) -> Self::Output {
                 $impl_fn(*self, *rhs)
             }
         }
@@ -53,17 +53,17 @@ macro_rules! impl_binary_op {
 
 /// Macro to implement assignment operations for both owned and borrowed rhs
 macro_rules! impl_assign_op {
-    ($trait:ident, $method:ident, $op:tt) => {
-        // Decimal<D> op= Decimal<D>
-        impl<const D: u32> $trait for Decimal<D> {
+    ($ty:ident, $trait:ident, $method:ident, $op:tt) => {
+        // T<D> op= T<D>
+        impl<const D: u32> $trait for $ty<D> {
             fn $method(&mut self, rhs: Self) {
                 *self = *self $op rhs;
             }
         }
 
-        // Decimal<D> op= &Decimal<D>
-        impl<const D: u32> $trait<&Decimal<D>> for Decimal<D> {
-            fn $method(&mut self, rhs: &Decimal<D>) {
+        // T<D> op= &T<D>
+        impl<const D: u32> $trait<&$ty<D>> for $ty<D> {
+            fn $method(&mut self, rhs: &$ty<D>) {
                 *self = *self $op rhs;
             }
         }
@@ -76,8 +76,8 @@ fn add_impl<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Decimal<D> {
     Decimal(a.0.checked_add(b.0).expect("attempt to add with overflow"))
 }
 
-impl_binary_op!(Add, add, add_impl);
-impl_assign_op!(AddAssign, add_assign, +);
+impl_binary_op!(Decimal, Add, add, add_impl);
+impl_assign_op!(Decimal, AddAssign, add_assign, +);
 
 // ========== Subtraction ==========
 
@@ -88,8 +88,8 @@ fn sub_impl<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Decimal<D> {
     )
 }
 
-impl_binary_op!(Sub, sub, sub_impl);
-impl_assign_op!(SubAssign, sub_assign, -);
+impl_binary_op!(Decimal, Sub, sub, sub_impl);
+impl_assign_op!(Decimal, SubAssign, sub_assign, -);
 
 // ========== Multiplication ==========
 
@@ -106,8 +106,8 @@ fn mul_impl<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Decimal<D> {
     )
 }
 
-impl_binary_op!(Mul, mul, mul_impl);
-impl_assign_op!(MulAssign, mul_assign, *);
+impl_binary_op!(Decimal, Mul, mul, mul_impl);
+impl_assign_op!(Decimal, MulAssign, mul_assign, *);
 
 // ========== Division ==========
 
@@ -126,8 +126,8 @@ fn div_impl<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Decimal<D> {
     Decimal(Uint128::try_from(result).expect("division result exceeds Uint128 range"))
 }
 
-impl_binary_op!(Div, div, div_impl);
-impl_assign_op!(DivAssign, div_assign, /);
+impl_binary_op!(Decimal, Div, div, div_impl);
+impl_assign_op!(Decimal, DivAssign, div_assign, /);
 
 // ========== Remainder ==========
 
@@ -138,8 +138,8 @@ fn rem_impl<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Decimal<D> {
     Decimal(a.0.checked_rem(b.0).unwrap())
 }
 
-impl_binary_op!(Rem, rem, rem_impl);
-impl_assign_op!(RemAssign, rem_assign, %);
+impl_binary_op!(Decimal, Rem, rem, rem_impl);
+impl_assign_op!(Decimal, RemAssign, rem_assign, %);
 
 // ========== Negation ==========
 
@@ -174,14 +174,8 @@ impl<const D: u32> Mul<Uint128> for Decimal<D> {
     type Output = Uint128;
 
     fn mul(self, rhs: Uint128) -> Self::Output {
-        // Use Uint256 to prevent overflow
-        let result = Uint256::from(self.0)
-            .checked_mul(Uint256::from(rhs))
-            .unwrap()
-            .checked_div(Uint256::from(Self::FRACTIONAL))
-            .unwrap();
-
-        Uint128::try_from(result).expect("multiplication result exceeds Uint128 range")
+        self.checked_mul_uint(rhs)
+            .expect("multiplication result exceeds Uint128 range")
     }
 }
 
@@ -253,10 +247,7 @@ impl<const D: u32> Div<Uint128> for Decimal<D> {
     type Output = Decimal<D>;
 
     fn div(self, rhs: Uint128) -> Self::Output {
-        if rhs.is_zero() {
-            panic!("Division by zero");
-        }
-        Decimal(self.0.checked_div(rhs).unwrap())
+        self.checked_div_uint(rhs).expect("Division by zero")
     }
 }
 
@@ -287,10 +278,183 @@ impl<const D: u32> Div<&Uint128> for &Decimal<D> {
     }
 }
 
+// Decimal<D> + Uint128 -> Decimal<D> (Uint128 is a whole number)
+fn add_uint_impl<const D: u32>(a: Decimal<D>, b: Uint128) -> Decimal<D> {
+    a.checked_add_uint(b).expect("attempt to add with overflow")
+}
+
+// Uint128 + Decimal<D> -> Decimal<D> (commutative)
+fn uint_add_impl<const D: u32>(a: Uint128, b: Decimal<D>) -> Decimal<D> {
+    add_uint_impl(b, a)
+}
+
+// Decimal<D> - Uint128 -> Decimal<D>
+fn sub_uint_impl<const D: u32>(a: Decimal<D>, b: Uint128) -> Decimal<D> {
+    a.checked_sub_uint(b).expect("attempt to subtract with overflow")
+}
+
+// Uint128 - Decimal<D> -> Decimal<D>
+fn uint_sub_impl<const D: u32>(a: Uint128, b: Decimal<D>) -> Decimal<D> {
+    Decimal::<D>::from(a) - b
+}
+
+// Decimal<D> % Uint128 -> Decimal<D>, the remainder after dividing by a whole number
+fn rem_uint_impl<const D: u32>(a: Decimal<D>, b: Uint128) -> Decimal<D> {
+    a.checked_rem_uint(b).expect("Division by zero")
+}
+
+/// Macro to implement an operation between Decimal<D> and Uint128 for all 4
+/// owned/borrowed combinations, in either operand order
+macro_rules! impl_uint_op {
+    ($lhs:ty, $rhs:ty, $trait:ident, $method:ident, $impl_fn:ident) => {
+        impl<const D: u32> $trait<$rhs> for $lhs {
+            type Output = Decimal<D>;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                $impl_fn(self, rhs)
+            }
+        }
+
+        impl<const D: u32> $trait<$rhs> for &$lhs {
+            type Output = Decimal<D>;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                $impl_fn(*self, rhs)
+            }
+        }
+
+        impl<const D: u32> $trait<&$rhs> for $lhs {
+            type Output = Decimal<D>;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                $impl_fn(self, *rhs)
+            }
+        }
+
+        impl<const D: u32> $trait<&$rhs> for &$lhs {
+            type Output = Decimal<D>;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                $impl_fn(*self, *rhs)
+            }
+        }
+    };
+}
+
+impl_uint_op!(Decimal<D>, Uint128, Add, add, add_uint_impl);
+impl_uint_op!(Uint128, Decimal<D>, Add, add, uint_add_impl);
+impl_uint_op!(Decimal<D>, Uint128, Sub, sub, sub_uint_impl);
+impl_uint_op!(Uint128, Decimal<D>, Sub, sub, uint_sub_impl);
+impl_uint_op!(Decimal<D>, Uint128, Rem, rem, rem_uint_impl);
+
+impl<const D: u32> AddAssign<Uint128> for Decimal<D> {
+    fn add_assign(&mut self, rhs: Uint128) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: u32> SubAssign<Uint128> for Decimal<D> {
+    fn sub_assign(&mut self, rhs: Uint128) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: u32> RemAssign<Uint128> for Decimal<D> {
+    fn rem_assign(&mut self, rhs: Uint128) {
+        *self = *self % rhs;
+    }
+}
+
+// ========== SignedDecimal ==========
+
+fn signed_add_impl<const D: u32>(a: SignedDecimal<D>, b: SignedDecimal<D>) -> SignedDecimal<D> {
+    a.checked_add(b).expect("attempt to add with overflow")
+}
+
+fn signed_sub_impl<const D: u32>(a: SignedDecimal<D>, b: SignedDecimal<D>) -> SignedDecimal<D> {
+    a.checked_sub(b).expect("attempt to subtract with overflow")
+}
+
+fn signed_mul_impl<const D: u32>(a: SignedDecimal<D>, b: SignedDecimal<D>) -> SignedDecimal<D> {
+    SignedDecimal::from_parts(a.magnitude * b.magnitude, a.negative != b.negative)
+}
+
+fn signed_div_impl<const D: u32>(a: SignedDecimal<D>, b: SignedDecimal<D>) -> SignedDecimal<D> {
+    SignedDecimal::from_parts(a.magnitude / b.magnitude, a.negative != b.negative)
+}
+
+fn signed_rem_impl<const D: u32>(a: SignedDecimal<D>, b: SignedDecimal<D>) -> SignedDecimal<D> {
+    SignedDecimal::from_parts(a.magnitude % b.magnitude, a.negative)
+}
+
+impl_binary_op!(SignedDecimal, Add, add, signed_add_impl);
+impl_assign_op!(SignedDecimal, AddAssign, add_assign, +);
+impl_binary_op!(SignedDecimal, Sub, sub, signed_sub_impl);
+impl_assign_op!(SignedDecimal, SubAssign, sub_assign, -);
+impl_binary_op!(SignedDecimal, Mul, mul, signed_mul_impl);
+impl_assign_op!(SignedDecimal, MulAssign, mul_assign, *);
+impl_binary_op!(SignedDecimal, Div, div, signed_div_impl);
+impl_assign_op!(SignedDecimal, DivAssign, div_assign, /);
+impl_binary_op!(SignedDecimal, Rem, rem, signed_rem_impl);
+impl_assign_op!(SignedDecimal, RemAssign, rem_assign, %);
+
+impl<const D: u32> Neg for SignedDecimal<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        SignedDecimal::from_parts(self.magnitude, !self.negative)
+    }
+}
+
+impl<const D: u32> Neg for &SignedDecimal<D> {
+    type Output = SignedDecimal<D>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+// ========== Decimal256Custom ==========
+
+fn wide_add_impl<const D: u32>(a: Decimal256Custom<D>, b: Decimal256Custom<D>) -> Decimal256Custom<D> {
+    a.checked_add(b).expect("attempt to add with overflow")
+}
+
+fn wide_sub_impl<const D: u32>(a: Decimal256Custom<D>, b: Decimal256Custom<D>) -> Decimal256Custom<D> {
+    a.checked_sub(b).expect("attempt to subtract with overflow")
+}
+
+fn wide_mul_impl<const D: u32>(a: Decimal256Custom<D>, b: Decimal256Custom<D>) -> Decimal256Custom<D> {
+    a.checked_mul(b).expect("multiplication result exceeds Uint256 range")
+}
+
+fn wide_div_impl<const D: u32>(a: Decimal256Custom<D>, b: Decimal256Custom<D>) -> Decimal256Custom<D> {
+    if b.is_zero() {
+        panic!("Division by zero");
+    }
+    a.checked_div(b).expect("division result exceeds Uint256 range")
+}
+
+fn wide_rem_impl<const D: u32>(a: Decimal256Custom<D>, b: Decimal256Custom<D>) -> Decimal256Custom<D> {
+    a.checked_rem(b).expect("Division by zero")
+}
+
+impl_binary_op!(Decimal256Custom, Add, add, wide_add_impl);
+impl_assign_op!(Decimal256Custom, AddAssign, add_assign, +);
+impl_binary_op!(Decimal256Custom, Sub, sub, wide_sub_impl);
+impl_assign_op!(Decimal256Custom, SubAssign, sub_assign, -);
+impl_binary_op!(Decimal256Custom, Mul, mul, wide_mul_impl);
+impl_assign_op!(Decimal256Custom, MulAssign, mul_assign, *);
+impl_binary_op!(Decimal256Custom, Div, div, wide_div_impl);
+impl_assign_op!(Decimal256Custom, DivAssign, div_assign, /);
+impl_binary_op!(Decimal256Custom, Rem, rem, wide_rem_impl);
+impl_assign_op!(Decimal256Custom, RemAssign, rem_assign, %);
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{Decimal6, Decimal9};
+    use std::str::FromStr;
 
     #[test]
     fn test_addition() {
@@ -357,6 +521,98 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
+    fn test_add_sub_uint128() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        let two = Uint128::new(2);
+        let three_and_half = Decimal6::from_str("3.5").unwrap();
+
+        assert_eq!(d + two, three_and_half);
+        assert_eq!(two + d, three_and_half);
+        assert_eq!(&d + &two, three_and_half);
+        assert_eq!(three_and_half - two, d);
+        assert_eq!(Uint128::new(5) - d, three_and_half);
+
+        let mut acc = d;
+        acc += two;
+        assert_eq!(acc, three_and_half);
+        acc -= Uint128::new(3);
+        assert_eq!(acc, Decimal6::from_str("0.5").unwrap());
+
+        assert_eq!(d.checked_sub_uint(two), None);
+        assert_eq!(Decimal6::MAX.checked_add_uint(Uint128::one()), None);
+        // Scaling the integer itself overflows
+        assert_eq!(Decimal6::ZERO.checked_add_uint(Uint128::MAX), None);
+    }
+
+    #[test]
+    fn test_saturating_add_sub_uint128() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        assert_eq!(d.saturating_add_uint(Uint128::new(2)), d + Uint128::new(2));
+        assert_eq!(Decimal6::MAX.saturating_add_uint(Uint128::new(1)), Decimal6::MAX);
+        assert_eq!(Decimal6::ZERO.saturating_add_uint(Uint128::MAX), Decimal6::MAX);
+
+        assert_eq!(Decimal6::from(7u64).saturating_sub_uint(Uint128::new(5)), Decimal6::from(2u64));
+        assert_eq!(Decimal6::ZERO.saturating_sub_uint(Uint128::new(5)), Decimal6::ZERO);
+        assert_eq!(d.saturating_sub_uint(Uint128::MAX), Decimal6::ZERO);
+    }
+
+    #[test]
+    fn test_checked_div_rem_uint128() {
+        let ten = Decimal6::from_str("10.0").unwrap();
+        assert_eq!(ten.checked_div_uint(Uint128::zero()), None);
+        assert_eq!(ten.checked_div_uint(Uint128::new(3)), Some(Decimal6::from_str("3.333333").unwrap()));
+        assert_eq!(ten.checked_div_uint(Uint128::new(4)), Some(ten / Uint128::new(4)));
+
+        assert_eq!(ten.checked_rem_uint(Uint128::zero()), None);
+        assert_eq!(ten.checked_rem_uint(Uint128::new(3)), Some(Decimal6::ONE));
+        let d = Decimal6::from_str("7.5").unwrap();
+        assert_eq!(d.checked_rem_uint(Uint128::new(2)), Some(d % Decimal6::from(2u64)));
+        // Divisors that cannot be represented exceed every value
+        assert_eq!(Decimal6::MAX.checked_rem_uint(Uint128::MAX), Some(Decimal6::MAX));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_rem_uint128() {
+        let d = Decimal6::from_str("7.5").unwrap();
+        let two = Uint128::new(2);
+        assert_eq!(d % two, Decimal6::from_str("1.5").unwrap());
+        assert_eq!(&d % &two, d % two);
+        assert_eq!(d % Uint128::new(10), d);
+
+        // Whole quotient times the divisor plus the remainder recovers the value
+        for n in [1u128, 2, 3, 7, 1_000] {
+            let n = Uint128::new(n);
+            let whole = (d / n).integer_part();
+            assert_eq!(Decimal6::from(whole * n) + d % n, d);
+        }
+
+        let mut balance = Decimal6::from_str("1234.56").unwrap();
+        balance %= Uint128::new(100);
+        assert_eq!(balance, Decimal6::from_str("34.56").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_rem_uint128_by_zero_panics() {
+        let _ = Decimal6::ONE % Uint128::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_uint128_overflow_panics() {
+        let _ = Decimal6::MAX + Uint128::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn test_sub_uint128_underflow_panics() {
+        let _ = Decimal6::ONE - Uint128::new(2);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
     fn test_reference_operations() {
         let a = Decimal::<6>(Uint128::new(1_000_000));
         let b = Decimal::<6>(Uint128::new(2_000_000));
@@ -417,4 +673,65 @@ mod tests {
         assert_eq!(d6.atomics(), 1_000_000);
         assert_eq!(d9.atomics(), 1_000_000_000);
     }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_decimal256_operations() {
+        let a = Decimal256Custom::<6>::raw(1_500_000); // 1.5
+        let b = Decimal256Custom::<6>::raw(500_000); // 0.5
+        assert_eq!(a + b, Decimal256Custom::raw(2_000_000));
+        assert_eq!(&a - &b, Decimal256Custom::raw(1_000_000));
+        assert_eq!(a * b, Decimal256Custom::raw(750_000));
+        assert_eq!(a / b, Decimal256Custom::raw(3_000_000));
+        assert_eq!(a % b, Decimal256Custom::ZERO);
+
+        let mut c = a;
+        c += b;
+        c *= b;
+        assert_eq!(c, Decimal256Custom::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_decimal256_division_by_zero_panics() {
+        let _ = Decimal256Custom::<6>::ONE / Decimal256Custom::ZERO;
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_signed_operations() {
+        let a = SignedDecimal::<6>::raw(1_500_000); // 1.5
+        let b = SignedDecimal::<6>::raw(-4_000_000); // -4.0
+
+        assert_eq!(a + b, SignedDecimal::raw(-2_500_000));
+        assert_eq!(a - b, SignedDecimal::raw(5_500_000));
+        assert_eq!(a * b, SignedDecimal::raw(-6_000_000));
+        assert_eq!(b / a, SignedDecimal::raw(-2_666_666));
+        assert_eq!(b % a, SignedDecimal::raw(-1_000_000));
+        assert_eq!(-b, SignedDecimal::raw(4_000_000));
+        assert_eq!(-&a, SignedDecimal::raw(-1_500_000));
+
+        // All four owned/reference combinations
+        assert_eq!(&a + b, a + b);
+        assert_eq!(a + &b, a + b);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a * &b, a * b);
+
+        let mut c = a;
+        c -= b;
+        c *= &b;
+        assert_eq!(c, SignedDecimal::raw(-22_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_signed_add_overflow() {
+        let _ = SignedDecimal::<6>::MIN + SignedDecimal::NEGATIVE_ONE;
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_signed_div_by_zero() {
+        let _ = SignedDecimal::<6>::NEGATIVE_ONE / SignedDecimal::ZERO;
+    }
 }
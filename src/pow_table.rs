@@ -0,0 +1,118 @@
+use crate::Decimal;
+use alloc::vec::Vec;
+
+/// Precomputed powers of a fixed base for repeated exponentiation.
+///
+/// Stores `base^(2^k)` for every `k` until the value overflows, so any `u32`
+/// exponent can be composed from its binary decomposition with at most one
+/// multiplication per set bit. Useful when the same per-block rate is raised
+/// to many different block counts.
+///
+/// # Example
+/// ```
+/// use cosmwasm_custom_decimal::{Decimal6, PowTable};
+/// use std::str::FromStr;
+///
+/// let rate = Decimal6::from_str("1.5").unwrap();
+/// let table = PowTable::new(rate);
+/// assert_eq!(table.pow(3), Decimal6::from_str("3.375").unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowTable<const D: u32> {
+    factors: Vec<Decimal<D>>,
+}
+
+impl<const D: u32> PowTable<D> {
+    /// Precompute the `base^(2^k)` factors for `base`.
+    pub fn new(base: Decimal<D>) -> Self {
+        let mut factors = Vec::with_capacity(u32::BITS as usize);
+        let mut current = Some(base);
+        while let Some(factor) = current {
+            factors.push(factor);
+            if factors.len() == u32::BITS as usize {
+                break;
+            }
+            current = factor.checked_mul(factor);
+        }
+        Self { factors }
+    }
+
+    /// Returns the base the table was built for.
+    pub fn base(&self) -> Decimal<D> {
+        self.factors[0]
+    }
+
+    /// Checked power. Returns `None` on overflow.
+    pub fn checked_pow(&self, exp: u32) -> Option<Decimal<D>> {
+        let mut result = Decimal::<D>::ONE;
+        let mut remaining = exp;
+        let mut k = 0;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                // A missing factor means base^(2^k) already overflowed
+                result = result.checked_mul(*self.factors.get(k)?)?;
+            }
+            remaining >>= 1;
+            k += 1;
+        }
+        Some(result)
+    }
+
+    /// Power function.
+    pub fn pow(&self, exp: u32) -> Decimal<D> {
+        self.checked_pow(exp).expect("overflow in pow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decimal6;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_matches_checked_pow() {
+        let two = Decimal6::from_str("2.0").unwrap();
+        let table = PowTable::new(two);
+        for exp in 0..=120 {
+            assert_eq!(table.checked_pow(exp), two.checked_pow(exp), "exp {}", exp);
+        }
+
+        let one_and_half = Decimal6::from_str("1.5").unwrap();
+        let table = PowTable::new(one_and_half);
+        for exp in 0..=6 {
+            assert_eq!(table.pow(exp), one_and_half.pow(exp), "exp {}", exp);
+        }
+
+        let ten = Decimal6::from_str("10.0").unwrap();
+        let table = PowTable::new(ten);
+        for exp in 0..=40 {
+            assert_eq!(table.checked_pow(exp), ten.checked_pow(exp), "exp {}", exp);
+        }
+    }
+
+    #[test]
+    fn test_base_below_one_never_overflows() {
+        let half = Decimal6::from_str("0.5").unwrap();
+        let table = PowTable::new(half);
+        assert_eq!(table.base(), half);
+        assert_eq!(table.pow(u32::MAX), Decimal6::ZERO);
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let table = PowTable::new(Decimal6::ZERO);
+        assert_eq!(table.pow(0), Decimal6::ONE);
+        assert_eq!(table.pow(5), Decimal6::ZERO);
+
+        let table = PowTable::new(Decimal6::ONE);
+        assert_eq!(table.pow(u32::MAX), Decimal6::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in pow")]
+    fn test_pow_overflow_panics() {
+        let table = PowTable::new(Decimal6::from_str("2.0").unwrap());
+        let _ = table.pow(200);
+    }
+}
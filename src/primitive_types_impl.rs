@@ -0,0 +1,66 @@
+use crate::{CustomDecimalError, Decimal};
+use primitive_types::{U128, U256};
+
+// These conversions carry the raw atomics, not the integer value: a
+// `Decimal18` of 1.5 becomes `1_500_000_000_000_000_000`, and reading a
+// `U256` back takes it as atomics at precision `D`. This differs from
+// `From<Uint128>`, which treats its input as a whole number.
+
+/// Raw atomics as a `U128`.
+impl<const D: u32> From<Decimal<D>> for U128 {
+    fn from(value: Decimal<D>) -> Self {
+        U128::from(value.0.u128())
+    }
+}
+
+/// Raw atomics as a `U256`.
+impl<const D: u32> From<Decimal<D>> for U256 {
+    fn from(value: Decimal<D>) -> Self {
+        U256::from(value.0.u128())
+    }
+}
+
+/// Reads a `U128` as raw atomics.
+impl<const D: u32> From<U128> for Decimal<D> {
+    fn from(value: U128) -> Self {
+        Decimal::raw(value.as_u128())
+    }
+}
+
+/// Reads a `U256` as raw atomics. Returns `Overflow` above `u128::MAX`.
+impl<const D: u32> TryFrom<U256> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        u128::try_from(value)
+            .map(Decimal::raw)
+            .map_err(|_| CustomDecimalError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decimal18, Decimal6};
+    use core::str::FromStr;
+
+    #[test]
+    fn test_u256_round_trip() {
+        let d = Decimal18::from_str("1.5").unwrap();
+        let wide = U256::from(d);
+        assert_eq!(wide, U256::from(1_500_000_000_000_000_000u128));
+        assert_eq!(Decimal18::try_from(wide), Ok(d));
+
+        assert_eq!(Decimal18::try_from(U256::from(Decimal18::MAX)), Ok(Decimal18::MAX));
+        assert_eq!(Decimal18::try_from(U256::from(u128::MAX) + 1), Err(CustomDecimalError::Overflow));
+    }
+
+    #[test]
+    fn test_u128_round_trip() {
+        let d = Decimal6::from_str("1.5").unwrap();
+        let narrow = U128::from(d);
+        assert_eq!(narrow, U128::from(1_500_000u128));
+        assert_eq!(Decimal6::from(narrow), d);
+        assert_eq!(Decimal6::from(U128::MAX), Decimal6::MAX);
+    }
+}
@@ -0,0 +1,80 @@
+//! `proptest` support, enabled by the `proptest` feature.
+//!
+//! `any::<Decimal<D>>()` samples the raw atomics across the whole range, and
+//! [`decimal_in_range`] restricts sampling to caller-chosen bounds.
+//!
+//! # Example
+//! ```
+//! use cosmwasm_custom_decimal::proptest_strategy::decimal_in_range;
+//! use cosmwasm_custom_decimal::Decimal6;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(price in decimal_in_range(Decimal6::ONE, Decimal6::from(100u64)))| {
+//!     prop_assert!(price >= Decimal6::ONE);
+//! });
+//! ```
+
+use crate::Decimal;
+use core::ops::RangeInclusive;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Map, Strategy};
+
+/// Samples the raw `u128` atomics uniformly from `ZERO` to `MAX`.
+impl<const D: u32> Arbitrary for Decimal<D> {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<u128>, fn(u128) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        decimal_in_range(Decimal::ZERO, Decimal::MAX)
+    }
+}
+
+/// Strategy producing values in `min..=max`, uniform over the atomics.
+///
+/// # Panics
+///
+/// Panics if `min > max`.
+pub fn decimal_in_range<const D: u32>(
+    min: Decimal<D>,
+    max: Decimal<D>,
+) -> Map<RangeInclusive<u128>, fn(u128) -> Decimal<D>> {
+    assert!(min <= max, "decimal_in_range requires min <= max");
+    (min.0.u128()..=max.0.u128()).prop_map(Decimal::raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decimal6;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_values_parse_back(value in any::<Decimal6>()) {
+            prop_assert_eq!(value.to_string().parse::<Decimal6>().unwrap(), value);
+        }
+
+        #[test]
+        fn test_range_is_respected(
+            value in decimal_in_range(Decimal6::percent(50), Decimal6::from(2u64)),
+        ) {
+            prop_assert!(value >= Decimal6::percent(50) && value <= Decimal6::from(2u64));
+        }
+
+        // With b >= 1 the product loses under one unit, and dividing by b
+        // cannot magnify that loss
+        #[test]
+        fn test_mul_then_div_within_one_ulp(
+            a in decimal_in_range(Decimal6::ZERO, Decimal6::from(1_000_000_000_000u64)),
+            b in decimal_in_range(Decimal6::ONE, Decimal6::from(1_000_000u64)),
+        ) {
+            prop_assert!(((a * b) / b).approx_eq(a, a.ulp()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal_in_range requires min <= max")]
+    fn test_inverted_range_panics() {
+        let _ = decimal_in_range(Decimal6::ONE, Decimal6::ZERO);
+    }
+}
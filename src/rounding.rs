@@ -0,0 +1,87 @@
+use cosmwasm_std::Uint256;
+
+/// Rounding strategy for operations that discard precision.
+///
+/// `Decimal<D>` is unsigned, so `Truncate` (toward zero) and `Floor` (toward
+/// negative infinity) behave identically; both are provided for parity with
+/// signed semantics.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward zero (the behavior of the plain operators)
+    #[default]
+    Truncate,
+    /// Round to nearest, ties away from zero
+    HalfUp,
+    /// Round to nearest, ties to the even neighbour (banker's rounding)
+    HalfEven,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round toward negative infinity
+    Floor,
+}
+
+/// Divide `numerator` by a nonzero `divisor`, rounding the quotient per `mode`.
+///
+/// The decision compares `2 * remainder` against `divisor`, so it is exact.
+/// A nonzero remainder implies `divisor >= 2`, so rounding up cannot overflow.
+pub(crate) fn div_rounded(numerator: Uint256, divisor: Uint256, mode: RoundingMode) -> Uint256 {
+    let quotient = numerator / divisor;
+    let remainder = numerator % divisor;
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    // remainder < divisor, so comparing against divisor - remainder avoids doubling
+    let round_up = match mode {
+        RoundingMode::Truncate | RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::HalfUp => remainder >= divisor - remainder,
+        RoundingMode::HalfEven => match remainder.cmp(&(divisor - remainder)) {
+            core::cmp::Ordering::Less => false,
+            core::cmp::Ordering::Greater => true,
+            core::cmp::Ordering::Equal => quotient % Uint256::from(2u8) == Uint256::one(),
+        },
+    };
+
+    if round_up {
+        quotient + Uint256::one()
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn div(n: u128, d: u128, mode: RoundingMode) -> Uint256 {
+        div_rounded(Uint256::from(n), Uint256::from(d), mode)
+    }
+
+    #[test]
+    fn test_div_rounded_modes() {
+        // 7 / 2 = 3.5 (tie, odd quotient)
+        assert_eq!(div(7, 2, RoundingMode::Truncate), Uint256::from(3u8));
+        assert_eq!(div(7, 2, RoundingMode::Floor), Uint256::from(3u8));
+        assert_eq!(div(7, 2, RoundingMode::Ceil), Uint256::from(4u8));
+        assert_eq!(div(7, 2, RoundingMode::HalfUp), Uint256::from(4u8));
+        assert_eq!(div(7, 2, RoundingMode::HalfEven), Uint256::from(4u8));
+
+        // 5 / 2 = 2.5 (tie, even quotient)
+        assert_eq!(div(5, 2, RoundingMode::HalfUp), Uint256::from(3u8));
+        assert_eq!(div(5, 2, RoundingMode::HalfEven), Uint256::from(2u8));
+
+        // 10 / 3 = 3.33
+        assert_eq!(div(10, 3, RoundingMode::HalfUp), Uint256::from(3u8));
+        assert_eq!(div(10, 3, RoundingMode::HalfEven), Uint256::from(3u8));
+        assert_eq!(div(10, 3, RoundingMode::Ceil), Uint256::from(4u8));
+
+        // 20 / 3 = 6.67
+        assert_eq!(div(20, 3, RoundingMode::HalfUp), Uint256::from(7u8));
+        assert_eq!(div(20, 3, RoundingMode::HalfEven), Uint256::from(7u8));
+        assert_eq!(div(20, 3, RoundingMode::Truncate), Uint256::from(6u8));
+
+        // Exact division ignores the mode
+        assert_eq!(div(9, 3, RoundingMode::Ceil), Uint256::from(3u8));
+    }
+}
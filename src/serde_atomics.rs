@@ -0,0 +1,85 @@
+//! Serialize a `Decimal<D>` as its raw `u128` atomics, a bare number.
+//!
+//! Use it per field with `#[serde(with = "cosmwasm_custom_decimal::serde_atomics")]`.
+//! The number carries no precision, so it is read back into `Decimal<D>`
+//! without scaling and only round-trips into the same `D`. This trades
+//! `cosmwasm_std::Decimal` compatibility for compact state.
+//!
+//! # Example
+//! ```
+//! use cosmwasm_custom_decimal::Decimal9;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Tick {
+//!     #[serde(with = "cosmwasm_custom_decimal::serde_atomics")]
+//!     price: Decimal9,
+//! }
+//!
+//! let tick = Tick { price: Decimal9::raw(1_500_000_000) }; // 1.5
+//! assert_eq!(serde_json::to_string(&tick).unwrap(), r#"{"price":1500000000}"#);
+//! ```
+
+use crate::Decimal;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize the atomics of `value` as a `u128` number.
+pub fn serialize<S, const D: u32>(value: &Decimal<D>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u128(value.0.u128())
+}
+
+/// Deserialize a `u128` number as the atomics of a `Decimal<D>`.
+pub fn deserialize<'de, De, const D: u32>(deserializer: De) -> Result<Decimal<D>, De::Error>
+where
+    De: Deserializer<'de>,
+{
+    u128::deserialize(deserializer).map(Decimal::raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal18, Decimal9};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tick {
+        #[serde(with = "crate::serde_atomics")]
+        price: Decimal9,
+    }
+
+    #[test]
+    fn test_serializes_bare_number() {
+        let tick = Tick { price: Decimal9::raw(1_500_000_000) };
+        let json = serde_json::to_string(&tick).unwrap();
+        assert_eq!(json, r#"{"price":1500000000}"#);
+        assert_eq!(serde_json::from_str::<Tick>(&json).unwrap(), tick);
+    }
+
+    #[test]
+    fn test_roundtrip_exact() {
+        for price in [Decimal9::ZERO, Decimal9::raw(1), Decimal9::MAX] {
+            let json = serde_json::to_string(&Tick { price }).unwrap();
+            assert_eq!(serde_json::from_str::<Tick>(&json).unwrap().price, price);
+        }
+
+        // Strings and negative numbers are not atomics
+        assert!(serde_json::from_str::<Tick>(r#"{"price":"1.5"}"#).is_err());
+        assert!(serde_json::from_str::<Tick>(r#"{"price":-1}"#).is_err());
+    }
+
+    #[test]
+    fn test_precision_comes_from_the_type() {
+        #[derive(Deserialize)]
+        struct Wide {
+            #[serde(with = "crate::serde_atomics")]
+            price: Decimal18,
+        }
+
+        // The same number means 1.5 at D = 9 but 0.0000000015 at D = 18
+        let wide: Wide = serde_json::from_str(r#"{"price":1500000000}"#).unwrap();
+        assert_eq!(wide.price, Decimal18::raw(1_500_000_000));
+    }
+}
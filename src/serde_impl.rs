@@ -1,40 +1,120 @@
-use crate::{pow10, Decimal};
-use cosmwasm_std::Uint128;
+use crate::{pow10, strip_separators, BoundedDecimal, Decimal, Decimal256Custom, SignedDecimal};
+use crate::signed::split_sign;
+use cosmwasm_std::{Uint128, Uint256};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Custom serialization for Decimal<D> to match cosmwasm_std::Decimal's format
 ///
 /// Serializes as a string with 18 decimal places (e.g., "1.500000000000000000")
 /// even though internally we may store fewer decimal places.
 /// This ensures storage compatibility with cosmwasm_std::Decimal.
+///
+/// Non-human-readable serializers (e.g. bincode, postcard) receive the raw `u128`
+/// atomics instead. That form is compact but carries no precision information, so
+/// it only round-trips into the same `D`.
 impl<const D: u32> Serialize for Decimal<D> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // Get integer and fractional parts from D-decimal atomics
-        let integer = self.0.u128() / Self::FRACTIONAL;
-        let fraction_d = self.0.u128() % Self::FRACTIONAL;
-
-        // Format similar to cosmwasm_std::Decimal - compact format without trailing zeros
-        if fraction_d == 0 {
-            // No fractional part, just output the integer
-            serializer.serialize_str(&integer.to_string())
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u128(self.0.u128());
+        }
+
+        serializer.serialize_str(&to_std_string(self))
+    }
+}
+
+/// Format as a compact `cosmwasm_std::Decimal`-style string, e.g. "1.5"
+pub(crate) fn to_std_string<const D: u32>(value: &Decimal<D>) -> String {
+    // Get integer and fractional parts from D-decimal atomics
+    let integer = value.0.u128() / Decimal::<D>::FRACTIONAL;
+    let fraction_d = value.0.u128() % Decimal::<D>::FRACTIONAL;
+
+    // Format similar to cosmwasm_std::Decimal - compact format without trailing zeros
+    if fraction_d == 0 {
+        // No fractional part, just output the integer
+        integer.to_string()
+    } else {
+        // Scale the fractional part from D decimals to 18 decimals
+        let fraction_18 = if D >= 18 {
+            fraction_d / pow10(D - 18)
         } else {
-            // Scale the fractional part from D decimals to 18 decimals
-            let fraction_18 = if D >= 18 {
-                fraction_d / pow10(D - 18)
-            } else {
-                fraction_d * pow10(18 - D)
-            };
+            fraction_d * pow10(18 - D)
+        };
+
+        // Format with 18 decimal places and trim trailing zeros
+        let frac_str = format!("{:0>18}", fraction_18);
+        let trimmed = frac_str.trim_end_matches('0');
 
-            // Format with 18 decimal places and trim trailing zeros
-            let frac_str = format!("{:0>18}", fraction_18);
-            let trimmed = frac_str.trim_end_matches('0');
+        format!("{}.{}", integer, trimmed)
+    }
+}
 
-            serializer.serialize_str(&format!("{}.{}", integer, trimmed))
+/// How human-readable deserialization treats strings with more than `D`
+/// fractional digits.
+///
+/// Plain `Deserialize` uses the default: `Lossy`, or `Strict` with the
+/// `strict-deserialize` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeserializeMode {
+    /// Truncate extra digits, so 18-decimal `cosmwasm_std::Decimal` strings load
+    /// into lower precisions.
+    #[cfg_attr(not(feature = "strict-deserialize"), default)]
+    Lossy,
+    /// Reject extra digits unless they are all zeros, so `"1.500000000000000000"`
+    /// still loads into a `Decimal6` but `"1.1234567"` does not.
+    #[cfg_attr(feature = "strict-deserialize", default)]
+    Strict,
+}
+
+impl<const D: u32> Decimal<D> {
+    /// Deserialize with an explicit [`DeserializeMode`].
+    ///
+    /// The mode only affects human-readable formats; binary formats carry raw
+    /// atomics and are always exact.
+    pub fn deserialize_with_mode<'de, De>(
+        deserializer: De,
+        mode: DeserializeMode,
+    ) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return u128::deserialize(deserializer).map(Decimal::raw);
         }
+
+        deserializer.deserialize_str(DecimalVisitor::<D>(mode))
+    }
+
+    /// Strict deserialization for use with `#[serde(deserialize_with = "...")]`,
+    /// rejecting over-precise input instead of truncating it. Extra digits that
+    /// are all zeros are still accepted (see [`DeserializeMode::Strict`]).
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Order {
+    ///     #[serde(deserialize_with = "Decimal6::deserialize_strict")]
+    ///     price: Decimal6,
+    /// }
+    ///
+    /// assert!(serde_json::from_str::<Order>(r#"{"price":"1.5"}"#).is_ok());
+    /// assert!(serde_json::from_str::<Order>(r#"{"price":"1.500000000000000000"}"#).is_ok());
+    /// assert!(serde_json::from_str::<Order>(r#"{"price":"1.1234567"}"#).is_err());
+    /// ```
+    pub fn deserialize_strict<'de, De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        Self::deserialize_with_mode(deserializer, DeserializeMode::Strict)
     }
 }
 
@@ -42,16 +122,20 @@ impl<const D: u32> Serialize for Decimal<D> {
 ///
 /// Accepts strings in the format "1.500000000000000000" (18 decimals)
 /// or shorter formats like "1.5", and scales to D decimals internally.
+/// Digits beyond `D` follow [`DeserializeMode::default`]: truncated, or
+/// rejected unless all zeros with the `strict-deserialize` feature.
+///
+/// Non-human-readable deserializers read the raw `u128` atomics written by `Serialize`.
 impl<'de, const D: u32> Deserialize<'de> for Decimal<D> {
     fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
     where
         De: Deserializer<'de>,
     {
-        deserializer.deserialize_str(DecimalVisitor::<D>)
+        Self::deserialize_with_mode(deserializer, DeserializeMode::default())
     }
 }
 
-struct DecimalVisitor<const D: u32>;
+struct DecimalVisitor<const D: u32>(DeserializeMode);
 
 impl<'de, const D: u32> de::Visitor<'de> for DecimalVisitor<D> {
     type Value = Decimal<D>;
@@ -64,58 +148,221 @@ impl<'de, const D: u32> de::Visitor<'de> for DecimalVisitor<D> {
     where
         E: de::Error,
     {
-        // Parse the string as a decimal number
-        let parts: Vec<&str> = v.split('.').collect();
-
-        match parts.len() {
-            1 => {
-                // Integer only, e.g., "123"
-                let integer = parts[0]
-                    .parse::<u128>()
-                    .map_err(|_| E::custom(format!("Invalid integer part: {}", parts[0])))?;
-
-                Ok(Decimal(Uint128::from(
-                    integer * Decimal::<D>::FRACTIONAL,
-                )))
-            }
-            2 => {
-                // Integer and fractional parts, e.g., "123.456" or "1.500000000000000000"
-                let integer = parts[0]
-                    .parse::<u128>()
-                    .map_err(|_| E::custom(format!("Invalid integer part: {}", parts[0])))?;
-
-                let fractional_str = parts[1];
-
-                // Handle fractional part - could be 18 decimals (from Decimal) or fewer
-                let fractional_value = if fractional_str.len() <= D as usize {
-                    // Short format like "1.5" or format with D or fewer decimals
-                    let frac = fractional_str
-                        .parse::<u128>()
-                        .map_err(|_| E::custom(format!("Invalid fractional part: {}", fractional_str)))?;
-
-                    // Scale to D decimals
-                    frac * pow10(D - fractional_str.len() as u32)
-                } else {
-                    // Long format (more decimals than D)
-                    // Parse and scale down to D decimals
-                    let frac = fractional_str
-                        .parse::<u128>()
-                        .map_err(|_| E::custom(format!("Invalid fractional part: {}", fractional_str)))?;
-
-                    // Scale down from input decimals to D decimals
-                    let input_decimals = fractional_str.len() as u32;
-                    frac / pow10(input_decimals - D)
+        parse_std_str(v, self.0)
+    }
+}
+
+/// Parse a `cosmwasm_std::Decimal`-style string, truncating digits beyond `D`.
+///
+/// In [`DeserializeMode::Strict`], digits beyond `D` must all be zeros;
+/// anything that would be lost to truncation is an error instead.
+///
+/// Accepts the same `_` separators and leading `+` as `FromStr`. Scientific
+/// notation (e.g. "1.5e-6") is parsed strictly by `FromStr`, so it errors
+/// instead of truncating when the value needs more than `D` decimals.
+pub(crate) fn parse_std_str<const D: u32, E>(v: &str, mode: DeserializeMode) -> Result<Decimal<D>, E>
+where
+    E: de::Error,
+{
+    let v = &*strip_separators(v).map_err(E::custom)?;
+    if v.contains(['e', 'E']) {
+        return v.parse().map_err(E::custom);
+    }
+
+    // Parse the string as a decimal number
+    let parts: Vec<&str> = v.split('.').collect();
+    // `u128::from_str` accepts a leading '+', which `strip_separators` has already removed
+    let parse_digits = |part: &str| match part.bytes().all(|b| b.is_ascii_digit()) {
+        true => part.parse::<u128>().ok(),
+        false => None,
+    };
+
+    match parts.len() {
+        1 => {
+            // Integer only, e.g., "123"
+            let integer = parse_digits(parts[0])
+                .ok_or_else(|| E::custom(format!("Invalid integer part: {}", parts[0])))?;
+
+            let total_atomics = integer
+                .checked_mul(Decimal::<D>::FRACTIONAL)
+                .ok_or_else(|| E::custom("Overflow in decimal value"))?;
+
+            Ok(Decimal(Uint128::from(total_atomics)))
+        }
+        2 if v == "." => Err(E::custom(format!("Invalid decimal format: {}", v))),
+        2 => {
+            // Integer and fractional parts, e.g., "123.456" or "1.500000000000000000".
+            // Either may be empty, as in ".5" or "1."
+            let integer = match parts[0] {
+                "" => 0,
+                part => parse_digits(part)
+                    .ok_or_else(|| E::custom(format!("Invalid integer part: {}", part)))?,
+            };
+
+            let fractional_str = parts[1];
+
+            // Handle fractional part - could be 18 decimals (from Decimal) or fewer
+            let fractional_value = if fractional_str.len() <= D as usize {
+                // Short format like "1.5" or format with D or fewer decimals
+                let frac = match fractional_str {
+                    "" => 0,
+                    part => parse_digits(part)
+                        .ok_or_else(|| E::custom(format!("Invalid fractional part: {}", part)))?,
                 };
 
-                let total_atomics = integer
-                    .checked_mul(Decimal::<D>::FRACTIONAL)
-                    .and_then(|i| i.checked_add(fractional_value))
-                    .ok_or_else(|| E::custom("Overflow in decimal value"))?;
+                // Scale to D decimals
+                frac * pow10(D - fractional_str.len() as u32)
+            } else {
+                // Long format (more decimals than D)
+                if mode == DeserializeMode::Strict
+                    && fractional_str[D as usize..].bytes().any(|b| b != b'0')
+                {
+                    return Err(E::custom(format!(
+                        "Precision loss: {} has more than {} significant decimal places",
+                        v, D
+                    )));
+                }
+
+                // Parse and scale down to D decimals
+                let frac = parse_digits(fractional_str)
+                    .ok_or_else(|| E::custom(format!("Invalid fractional part: {}", fractional_str)))?;
+
+                // Scale down from input decimals to D decimals
+                let input_decimals = fractional_str.len() as u32;
+                frac / pow10(input_decimals - D)
+            };
+
+            let total_atomics = integer
+                .checked_mul(Decimal::<D>::FRACTIONAL)
+                .and_then(|i| i.checked_add(fractional_value))
+                .ok_or_else(|| E::custom("Overflow in decimal value"))?;
+
+            Ok(Decimal(Uint128::from(total_atomics)))
+        }
+        _ => Err(E::custom(format!("Invalid decimal format: {}", v))),
+    }
+}
+
+/// Serializes like `Decimal<D>` with a leading `-` for negative values, e.g. "-1.5"
+///
+/// Non-human-readable serializers receive a `(negative, atomics)` tuple.
+impl<const D: u32> Serialize for SignedDecimal<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() {
+            return (self.negative, self.magnitude.0.u128()).serialize(serializer);
+        }
+
+        if self.negative {
+            serializer.serialize_str(&format!("-{}", to_std_string(&self.magnitude)))
+        } else {
+            serializer.serialize_str(&to_std_string(&self.magnitude))
+        }
+    }
+}
+
+/// Accepts the `Decimal<D>` string format with an optional leading `-`
+impl<'de, const D: u32> Deserialize<'de> for SignedDecimal<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            let (negative, atomics) = <(bool, u128)>::deserialize(deserializer)?;
+            return Ok(SignedDecimal::from_parts(Decimal::raw(atomics), negative));
+        }
+
+        deserializer.deserialize_str(SignedDecimalVisitor::<D>)
+    }
+}
+
+struct SignedDecimalVisitor<const D: u32>;
+
+impl<'de, const D: u32> de::Visitor<'de> for SignedDecimalVisitor<D> {
+    type Value = SignedDecimal<D>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string representing a signed decimal number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let (negative, magnitude) = split_sign(v).map_err(E::custom)?;
+        Ok(SignedDecimal::from_parts(parse_std_str(magnitude, DeserializeMode::default())?, negative))
+    }
+}
 
-                Ok(Decimal(Uint128::from(total_atomics)))
-            }
-            _ => Err(E::custom(format!("Invalid decimal format: {}", v))),
+/// Serializes like `cosmwasm_std::Decimal256`: always a compact string with at
+/// most 18 decimal places, for both human-readable and binary formats.
+impl<const D: u32> Serialize for Decimal256Custom<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if D > 18 {
+            // Drop the digits that cosmwasm_std::Decimal256 cannot represent
+            let step = Uint256::from(pow10(D - 18));
+            let truncated = Decimal256Custom::<D>::new(self.atomics() / step * step);
+            return serializer.serialize_str(&truncated.to_string());
         }
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Accepts the `cosmwasm_std::Decimal256` string format, truncating digits
+/// beyond `D` like `Decimal<D>`.
+impl<'de, const D: u32> Deserialize<'de> for Decimal256Custom<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Decimal256Visitor::<D>)
+    }
+}
+
+struct Decimal256Visitor<const D: u32>;
+
+impl<'de, const D: u32> de::Visitor<'de> for Decimal256Visitor<D> {
+    type Value = Decimal256Custom<D>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string representing a decimal number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Decimal256Custom::parse(v, true).map_err(E::custom)
+    }
+}
+
+/// Serializes exactly like the inner `Decimal<D>`
+impl<const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> Serialize
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+/// Deserializes like `Decimal<D>`, then rejects values outside the bounds
+impl<'de, const D: u32, const MIN_BPS: u32, const MAX_BPS: u32> Deserialize<'de>
+    for BoundedDecimal<D, MIN_BPS, MAX_BPS>
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        let value = Decimal::<D>::deserialize(deserializer)?;
+        BoundedDecimal::new(value).map_err(de::Error::custom)
     }
 }
 
@@ -123,7 +370,6 @@ impl<'de, const D: u32> de::Visitor<'de> for DecimalVisitor<D> {
 mod tests {
     use super::*;
     use crate::{Decimal6, Decimal9, Decimal18};
-    use serde_json;
 
     #[test]
     fn test_serialize_compact_format() {
@@ -167,6 +413,13 @@ mod tests {
         assert_eq!(custom.0, Uint128::new(123_000_000)); // 123.0 in 6 decimals
     }
 
+    #[test]
+    fn test_deserialize_integer_overflow() {
+        let json = format!(r#""{}""#, u128::MAX);
+        let result: Result<Decimal<6>, _> = serde_json::from_str(&json);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_roundtrip() {
         let original = Decimal::<6>::raw(1_234_567); // 1.234567 in 6 decimals
@@ -183,6 +436,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "strict-deserialize"))]
     fn test_deserialize_precision_loss() {
         // When deserializing from 18 decimals, we lose precision beyond 6 decimals
         let json = r#""1.123456789012345678""#;
@@ -191,6 +445,66 @@ mod tests {
         assert_eq!(custom.0, Uint128::new(1_123_456));
     }
 
+    #[test]
+    #[cfg(not(feature = "strict-deserialize"))]
+    fn test_deserialize_mode() {
+        let over_precise = r#""1.1234567""#;
+
+        // FromStr and strict mode reject; the default is lossy and truncates
+        assert!(Decimal6::from_str_exact("1.1234567").is_err());
+        let lossy: Decimal6 = serde_json::from_str(over_precise).unwrap();
+        assert_eq!(lossy, Decimal6::raw(1_123_456));
+
+        let mut de = serde_json::Deserializer::from_str(over_precise);
+        assert!(Decimal6::deserialize_with_mode(&mut de, DeserializeMode::Strict).is_err());
+        let mut de = serde_json::Deserializer::from_str(over_precise);
+        assert_eq!(
+            Decimal6::deserialize_with_mode(&mut de, DeserializeMode::Lossy).unwrap(),
+            lossy
+        );
+
+        // Inputs within precision, or padded with zeros, parse identically in both modes
+        let mut de = serde_json::Deserializer::from_str(r#""1.123456""#);
+        assert_eq!(Decimal6::deserialize_strict(&mut de).unwrap(), lossy);
+        let mut de = serde_json::Deserializer::from_str(r#""1.123456000000000000""#);
+        assert_eq!(Decimal6::deserialize_strict(&mut de).unwrap(), lossy);
+        assert_eq!(DeserializeMode::default(), DeserializeMode::Lossy);
+    }
+
+    #[test]
+    fn test_deserialize_strict_field() {
+        #[derive(Deserialize)]
+        struct Order {
+            #[serde(deserialize_with = "Decimal6::deserialize_strict")]
+            price: Decimal6,
+        }
+
+        let order: Order = serde_json::from_str(r#"{"price":"1.5"}"#).unwrap();
+        assert_eq!(order.price, Decimal6::raw(1_500_000));
+        // StdDecimal output pads with zeros, which strict mode accepts
+        let order: Order = serde_json::from_str(r#"{"price":"1.500000000000000000"}"#).unwrap();
+        assert_eq!(order.price, Decimal6::raw(1_500_000));
+        assert!(serde_json::from_str::<Order>(r#"{"price":"1.500000000000000001"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "strict-deserialize")]
+    fn test_strict_deserialize_feature() {
+        assert!(serde_json::from_str::<Decimal6>(r#""1.123456789012345678""#).is_err());
+        assert!(serde_json::from_str::<Decimal6>(r#""1.1234561""#).is_err());
+        assert!(serde_json::from_str::<SignedDecimal<6>>(r#""-1.0000001""#).is_err());
+        assert!(serde_json::from_str::<Decimal256Custom<6>>(r#""1.0000001""#).is_err());
+
+        // Trailing zeros beyond D lose nothing, so StdDecimal output still loads
+        let d: Decimal6 = serde_json::from_str(r#""1.500000000000000000""#).unwrap();
+        assert_eq!(d, Decimal6::raw(1_500_000));
+        let d: Decimal256Custom<6> = serde_json::from_str(r#""1.123456000""#).unwrap();
+        assert_eq!(d, Decimal256Custom::raw(1_123_456));
+
+        // The feature is the same rule as DeserializeMode::Strict
+        assert_eq!(DeserializeMode::default(), DeserializeMode::Strict);
+    }
+
     // ========== Decimal9 tests ==========
 
     #[test]
@@ -216,6 +530,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "strict-deserialize"))]
     fn test_decimal9_from_18_decimals() {
         let json = r#""1.123456789012345678""#;
         let d9: Decimal9 = serde_json::from_str(json).unwrap();
@@ -269,4 +584,166 @@ mod tests {
         let d6: Decimal6 = serde_json::from_str(&json).unwrap();
         assert_eq!(d6.0, Uint128::new(1_500_000)); // 1.5 in 6 decimals
     }
+
+    // ========== Binary format tests ==========
+
+    #[test]
+    fn test_binary_serializes_raw_atomics() {
+        let d6 = Decimal6::raw(1_234_567);
+        let bytes = bincode::serialize(&d6).unwrap();
+        assert_eq!(bytes, 1_234_567u128.to_le_bytes());
+
+        let back: Decimal6 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, d6);
+    }
+
+    #[test]
+    fn test_binary_is_more_compact_than_string() {
+        let d18 = Decimal18::from_ratio(1u128, 3u128);
+        let binary = bincode::serialize(&d18).unwrap();
+        let as_string = bincode::serialize(&serde_json::to_string(&d18).unwrap()).unwrap();
+        assert_eq!(binary.len(), 16);
+        assert!(binary.len() < as_string.len());
+    }
+
+    #[test]
+    fn test_human_readable_and_binary_roundtrip() {
+        for d9 in [Decimal9::ZERO, Decimal9::ONE, Decimal9::MAX, Decimal9::raw(1)] {
+            let json = serde_json::to_string(&d9).unwrap();
+            assert!(json.starts_with('"'));
+            assert_eq!(serde_json::from_str::<Decimal9>(&json).unwrap(), d9);
+
+            let bytes = bincode::serialize(&d9).unwrap();
+            assert_eq!(bincode::deserialize::<Decimal9>(&bytes).unwrap(), d9);
+        }
+    }
+
+    #[test]
+    fn test_signed_serialize() {
+        let negative = SignedDecimal::<6>::raw(-1_500_000);
+        assert_eq!(serde_json::to_string(&negative).unwrap(), r#""-1.5""#);
+        assert_eq!(serde_json::to_string(&SignedDecimal::<6>::ONE).unwrap(), r#""1""#);
+        assert_eq!(serde_json::to_string(&SignedDecimal::<6>::ZERO).unwrap(), r#""0""#);
+    }
+
+    #[test]
+    fn test_signed_roundtrip() {
+        for value in [
+            SignedDecimal::<6>::raw(-1_500_000),
+            SignedDecimal::<6>::raw(1),
+            SignedDecimal::<6>::MIN,
+            SignedDecimal::<6>::MAX,
+            SignedDecimal::<6>::ZERO,
+        ] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<SignedDecimal<6>>(&json).unwrap(), value);
+
+            let bytes = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<SignedDecimal<6>>(&bytes).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_signed_deserialize_18_decimal_format() {
+        let d: SignedDecimal<6> = serde_json::from_str(r#""-1.500000000000000000""#).unwrap();
+        assert_eq!(d, SignedDecimal::raw(-1_500_000));
+
+        let zero: SignedDecimal<6> = serde_json::from_str(r#""-0""#).unwrap();
+        assert!(!zero.is_negative());
+
+        assert!(serde_json::from_str::<SignedDecimal<6>>(r#""--1""#).is_err());
+        assert!(serde_json::from_str::<SignedDecimal<6>>(r#""-+1""#).is_err());
+        assert!(serde_json::from_str::<SignedDecimal<6>>(r#""+-1""#).is_err());
+    }
+
+    #[test]
+    fn test_decimal256_matches_std_decimal256() {
+        use core::str::FromStr;
+
+        for input in ["0", "1", "1.5", "0.000000000000000001", "115792089237316195423570985008.5"] {
+            let custom = Decimal256Custom::<18>::from_str(input).unwrap();
+            let std = cosmwasm_std::Decimal256::from_str(input).unwrap();
+            let json = serde_json::to_string(&custom).unwrap();
+            assert_eq!(json, serde_json::to_string(&std).unwrap());
+            assert_eq!(serde_json::from_str::<Decimal256Custom<18>>(&json).unwrap(), custom);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-deserialize"))]
+    fn test_decimal256_cross_precision() {
+        let d24 = Decimal256Custom::<24>::raw(1_123_456_789_012_345_678_901_234);
+        let json = serde_json::to_string(&d24).unwrap();
+        assert_eq!(json, r#""1.123456789012345678""#);
+
+        let d6: Decimal256Custom<6> = serde_json::from_str(&json).unwrap();
+        assert_eq!(d6, Decimal256Custom::raw(1_123_456));
+
+        let bytes = bincode::serialize(&d6).unwrap();
+        assert_eq!(bincode::deserialize::<Decimal256Custom<6>>(&bytes).unwrap(), d6);
+
+        assert!(serde_json::from_str::<Decimal256Custom<6>>(r#""1.x""#).is_err());
+        assert!(serde_json::from_str::<Decimal256Custom<6>>(r#""abc""#).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_shorthand() {
+        let d: Decimal6 = serde_json::from_str(r#"".5""#).unwrap();
+        assert_eq!(d, Decimal6::raw(500_000));
+        let d: Decimal6 = serde_json::from_str(r#""1.""#).unwrap();
+        assert_eq!(d, Decimal6::ONE);
+        let signed: SignedDecimal<6> = serde_json::from_str(r#""-.5""#).unwrap();
+        assert_eq!(signed, SignedDecimal::raw(-500_000));
+        let wide: Decimal256Custom<6> = serde_json::from_str(r#"".5""#).unwrap();
+        assert_eq!(wide, Decimal256Custom::raw(500_000));
+
+        assert!(serde_json::from_str::<Decimal6>(r#"".""#).is_err());
+        assert!(serde_json::from_str::<Decimal256Custom<6>>(r#"".""#).is_err());
+    }
+
+    #[test]
+    fn test_bounded_serde() {
+        type FeeRate = BoundedDecimal<6, 10, 500>;
+
+        let fee = FeeRate::new(Decimal6::percent(3)).unwrap();
+        let json = serde_json::to_string(&fee).unwrap();
+        assert_eq!(json, serde_json::to_string(&Decimal6::percent(3)).unwrap());
+        assert_eq!(serde_json::from_str::<FeeRate>(&json).unwrap(), fee);
+
+        let err = serde_json::from_str::<FeeRate>(r#""0.1""#).unwrap_err();
+        assert!(err.to_string().contains("exceeds valid range"));
+    }
+
+    #[test]
+    fn test_deserialize_scientific_notation() {
+        let d: Decimal6 = serde_json::from_str(r#""2.5E3""#).unwrap();
+        assert_eq!(d, Decimal6::from(2500u64));
+
+        let d: Decimal9 = serde_json::from_str(r#""1.5e-6""#).unwrap();
+        assert_eq!(d, Decimal9::raw(1_500));
+
+        let signed: SignedDecimal<6> = serde_json::from_str(r#""-1e-6""#).unwrap();
+        assert_eq!(signed, SignedDecimal::raw(-1));
+
+        assert!(serde_json::from_str::<Decimal6>(r#""1.1234567e0""#).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_separators_and_plus() {
+        let d: Decimal6 = serde_json::from_str(r#""1_000.250_000""#).unwrap();
+        assert_eq!(d, Decimal6::raw(1_000_250_000));
+
+        let d: Decimal6 = serde_json::from_str(r#""+3.5""#).unwrap();
+        assert_eq!(d, Decimal6::raw(3_500_000));
+
+        let signed: SignedDecimal<6> = serde_json::from_str(r#""-1_000""#).unwrap();
+        assert_eq!(signed, SignedDecimal::raw(-1_000_000_000));
+
+        assert!(serde_json::from_str::<Decimal6>(r#""1__0""#).is_err());
+        assert!(serde_json::from_str::<Decimal6>(r#""1_.5""#).is_err());
+
+        for bad in [r#""1.+5""#, r#""+1.+5""#, r#"".+5""#, r#""1.+5000000000""#] {
+            assert!(serde_json::from_str::<Decimal6>(bad).is_err(), "{}", bad);
+        }
+    }
 }
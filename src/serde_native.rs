@@ -0,0 +1,131 @@
+//! Serialize a `Decimal<D>` at its native precision instead of the
+//! `cosmwasm_std::Decimal`-compatible 18-decimal form.
+//!
+//! Use it per field with `#[serde(with = "cosmwasm_custom_decimal::serde_native")]`.
+//! Output keeps all `D` significant decimals (trailing zeros trimmed), and input
+//! with more than `D` decimals is rejected rather than truncated, so the two
+//! directions are symmetric.
+//!
+//! This is **not** byte-compatible with `cosmwasm_std::Decimal`: a `Decimal<24>`
+//! writes 24 decimals, which `StdDecimal` cannot read, and 18-decimal strings
+//! fail to load into a lower precision. Keep the default impl for storage.
+//! Binary formats use the raw `u128` atomics, exactly as the default impl does.
+//!
+//! # Example
+//! ```
+//! use cosmwasm_custom_decimal::Decimal;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Quote {
+//!     #[serde(with = "cosmwasm_custom_decimal::serde_native")]
+//!     price: Decimal<24>,
+//! }
+//!
+//! let quote = Quote { price: Decimal::raw(1_000_000_000_000_000_000_000_001) };
+//! let json = serde_json::to_string(&quote).unwrap();
+//! assert_eq!(json, r#"{"price":"1.000000000000000000000001"}"#);
+//! ```
+
+use crate::Decimal;
+use alloc::string::ToString;
+use core::fmt;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+/// Serialize `value` with exactly its own `D` decimals (trailing zeros trimmed).
+pub fn serialize<S, const D: u32>(value: &Decimal<D>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        return serializer.serialize_u128(value.0.u128());
+    }
+
+    serializer.serialize_str(&value.to_string())
+}
+
+/// Deserialize a string with at most `D` decimals, rejecting anything more precise.
+pub fn deserialize<'de, De, const D: u32>(deserializer: De) -> Result<Decimal<D>, De::Error>
+where
+    De: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return u128::deserialize(deserializer).map(Decimal::raw);
+    }
+
+    deserializer.deserialize_str(NativeVisitor::<D>)
+}
+
+struct NativeVisitor<const D: u32>;
+
+impl<'de, const D: u32> de::Visitor<'de> for NativeVisitor<D> {
+    type Value = Decimal<D>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a decimal string with at most {} decimal places", D)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Decimal::from_str_exact(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal, Decimal6};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Native<const D: u32> {
+        #[serde(with = "crate::serde_native")]
+        value: Decimal<D>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compat<const D: u32> {
+        value: Decimal<D>,
+    }
+
+    #[test]
+    fn test_same_output_when_no_rescaling_needed() {
+        let value = Decimal6::raw(1_500_000);
+        assert_eq!(serde_json::to_string(&Native { value }).unwrap(), r#"{"value":"1.5"}"#);
+        assert_eq!(serde_json::to_string(&Compat { value }).unwrap(), r#"{"value":"1.5"}"#);
+    }
+
+    #[test]
+    fn test_output_differs_beyond_18_decimals() {
+        let value = Decimal::<24>::raw(1_123_456_789_012_345_678_901_234);
+        assert_eq!(
+            serde_json::to_string(&Native { value }).unwrap(),
+            r#"{"value":"1.123456789012345678901234"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Compat { value }).unwrap(),
+            r#"{"value":"1.123456789012345678"}"#
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-deserialize"))]
+    fn test_roundtrip_and_strict_input() {
+        for value in [Decimal::<24>::ZERO, Decimal::<24>::raw(1), Decimal::<24>::MAX] {
+            let json = serde_json::to_string(&Native { value }).unwrap();
+            assert_eq!(serde_json::from_str::<Native<24>>(&json).unwrap(), Native { value });
+
+            let bytes = bincode::serialize(&Native { value }).unwrap();
+            assert_eq!(bincode::deserialize::<Native<24>>(&bytes).unwrap(), Native { value });
+        }
+
+        // 18-decimal StdDecimal output only loads through the default impl
+        let std_json = r#"{"value":"1.500000000000000001"}"#;
+        assert!(serde_json::from_str::<Native<6>>(std_json).is_err());
+        assert_eq!(
+            serde_json::from_str::<Compat<6>>(std_json).unwrap().value,
+            Decimal6::raw(1_500_000)
+        );
+    }
+}
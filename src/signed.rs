@@ -0,0 +1,569 @@
+use crate::{CustomDecimalError, Decimal};
+use cosmwasm_schema::schemars::gen::SchemaGenerator;
+use cosmwasm_schema::schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use cosmwasm_schema::schemars::JsonSchema;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+/// A signed fixed-point decimal with configurable decimal places.
+///
+/// Stored as a sign plus a [`Decimal<D>`] magnitude, so the range is symmetric
+/// (`-Decimal::<D>::MAX..=Decimal::<D>::MAX`) and every `Decimal<D>` converts
+/// without loss. Zero is never negative.
+///
+/// Multiplication and division truncate the magnitude, i.e. round toward zero.
+/// Serialization matches `Decimal<D>` with a leading `-` for negative values.
+///
+/// # Examples
+///
+/// ```
+/// use cosmwasm_custom_decimal::SignedDecimal;
+/// use std::str::FromStr;
+///
+/// let a = SignedDecimal::<6>::from_str("1.5").unwrap();
+/// let b = SignedDecimal::<6>::from_str("4").unwrap();
+/// let delta = a - b; // -2.5
+/// assert!(delta.is_negative());
+/// assert_eq!(delta.abs(), SignedDecimal::<6>::from_str("2.5").unwrap());
+/// assert_eq!((-delta).to_string(), "2.5");
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SignedDecimal<const D: u32> {
+    pub(crate) negative: bool,
+    pub(crate) magnitude: Decimal<D>,
+}
+
+impl<const D: u32> SignedDecimal<D> {
+    // ========== Constants ==========
+
+    /// The fractional multiplier: 10^D
+    pub const FRACTIONAL: u128 = Decimal::<D>::FRACTIONAL;
+
+    /// Zero decimal value
+    pub const ZERO: Self = Self::positive(Decimal::<D>::ZERO);
+
+    /// One decimal value (1.0)
+    pub const ONE: Self = Self::positive(Decimal::<D>::ONE);
+
+    /// Negative one decimal value (-1.0)
+    pub const NEGATIVE_ONE: Self = Self {
+        negative: true,
+        magnitude: Decimal::<D>::ONE,
+    };
+
+    /// Maximum decimal value
+    pub const MAX: Self = Self::positive(Decimal::<D>::MAX);
+
+    /// Minimum decimal value
+    pub const MIN: Self = Self {
+        negative: true,
+        magnitude: Decimal::<D>::MAX,
+    };
+
+    /// Number of decimal places
+    pub const DECIMAL_PLACES: u32 = D;
+
+    // ========== Construction ==========
+
+    const fn positive(magnitude: Decimal<D>) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    /// Create from a magnitude and a sign. A zero magnitude is always non-negative.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::{Decimal, SignedDecimal};
+    /// let d = SignedDecimal::from_parts(Decimal::<6>::ONE, true); // -1.0
+    /// assert_eq!(d, SignedDecimal::<6>::NEGATIVE_ONE);
+    /// ```
+    pub const fn from_parts(magnitude: Decimal<D>, negative: bool) -> Self {
+        Self {
+            negative: negative && magnitude.0.u128() != 0,
+            magnitude,
+        }
+    }
+
+    /// Create from raw signed atomic units.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// let d = SignedDecimal::<6>::raw(-1_500_000); // -1.5
+    /// ```
+    pub const fn raw(atomics: i128) -> Self {
+        Self::from_parts(Decimal::raw(atomics.unsigned_abs()), atomics < 0)
+    }
+
+    /// Create from signed atomics with specified decimal places, scaling the
+    /// magnitude like [`Decimal::checked_from_atomics`]. Extra digits are
+    /// truncated toward zero.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// let d = SignedDecimal::<6>::from_signed_atomics(-15i128, 1).unwrap();
+    /// assert_eq!(d, SignedDecimal::<6>::raw(-1_500_000)); // -1.5
+    /// ```
+    pub fn from_signed_atomics(atomics: i128, decimal_places: u32) -> Result<Self, CustomDecimalError> {
+        let magnitude = Decimal::checked_from_atomics(atomics.unsigned_abs(), decimal_places)?;
+        Ok(Self::from_parts(magnitude, atomics < 0))
+    }
+
+    // ========== Accessors ==========
+
+    /// Returns the absolute value as an unsigned `Decimal<D>`.
+    pub const fn magnitude(&self) -> Decimal<D> {
+        self.magnitude
+    }
+
+    /// Returns the number of decimal places.
+    pub const fn decimal_places(&self) -> u32 {
+        D
+    }
+
+    /// Returns true if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns true if the value is strictly less than zero.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns true if the value is strictly greater than zero.
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Returns the absolute value. Never overflows because the range is symmetric.
+    pub const fn abs(self) -> Self {
+        Self::positive(self.magnitude)
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign.
+    pub fn signum(self) -> Self {
+        if self.is_zero() {
+            Self::ZERO
+        } else if self.negative {
+            Self::NEGATIVE_ONE
+        } else {
+            Self::ONE
+        }
+    }
+
+    // ========== Checked Operations ==========
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if self.negative == other.negative {
+            let magnitude = self.magnitude.checked_add(other.magnitude)?;
+            return Some(Self::from_parts(magnitude, self.negative));
+        }
+
+        // Opposite signs: the larger magnitude decides the sign
+        if self.magnitude >= other.magnitude {
+            Some(Self::from_parts(self.magnitude - other.magnitude, self.negative))
+        } else {
+            Some(Self::from_parts(other.magnitude - self.magnitude, other.negative))
+        }
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_mul(other.magnitude)?;
+        Some(Self::from_parts(magnitude, self.negative != other.negative))
+    }
+
+    /// Checked division. Returns `None` on division by zero or overflow.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_div(other.magnitude)?;
+        Some(Self::from_parts(magnitude, self.negative != other.negative))
+    }
+
+    /// Checked remainder. Returns `None` on division by zero.
+    ///
+    /// The result has the sign of `self`, matching Rust's `%` on integers.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_rem(other.magnitude)?;
+        Some(Self::from_parts(magnitude, self.negative))
+    }
+
+    /// Euclidean division: the whole-number quotient rounded so that the
+    /// remainder is non-negative, matching `i128::div_euclid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on division by zero or if the quotient exceeds the range.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// use std::str::FromStr;
+    /// let a = SignedDecimal::<6>::from_str("-7.5").unwrap();
+    /// let b = SignedDecimal::<6>::from_str("2").unwrap();
+    /// assert_eq!(a.div_euclid(b), SignedDecimal::<6>::from_str("-4").unwrap());
+    /// assert_eq!(a.rem_euclid(b), SignedDecimal::<6>::from_str("0.5").unwrap());
+    /// ```
+    pub fn div_euclid(self, other: Self) -> Self {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        self.checked_div_euclid(other).expect("division result exceeds Decimal range")
+    }
+
+    /// Euclidean remainder, always in `[0, |other|)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on division by zero.
+    pub fn rem_euclid(self, other: Self) -> Self {
+        self.checked_rem_euclid(other).expect("Division by zero")
+    }
+
+    /// Checked Euclidean division. Returns `None` on division by zero or overflow.
+    pub fn checked_div_euclid(self, other: Self) -> Option<Self> {
+        let (quotient, remainder) = self.magnitude.checked_div_rem(other.magnitude)?;
+        let quotient = if self.negative && !remainder.is_zero() {
+            quotient.checked_add(Decimal::ONE)?
+        } else {
+            quotient
+        };
+        Some(Self::from_parts(quotient, self.negative != other.negative))
+    }
+
+    /// Checked Euclidean remainder. Returns `None` on division by zero.
+    pub fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+        let remainder = self.magnitude.checked_rem(other.magnitude)?;
+        if self.negative && !remainder.is_zero() {
+            Some(Self::from_parts(other.magnitude - remainder, false))
+        } else {
+            Some(Self::from_parts(remainder, false))
+        }
+    }
+
+    // ========== Saturating Operations ==========
+
+    /// Saturating addition. Returns `MAX` or `MIN` on overflow.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .unwrap_or(if self.negative { Self::MIN } else { Self::MAX })
+    }
+
+    /// Saturating subtraction. Returns `MAX` or `MIN` on overflow.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.saturating_add(-other)
+    }
+
+    /// Saturating multiplication. Returns `MAX` or `MIN` on overflow.
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other).unwrap_or(if self.negative != other.negative {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+}
+
+// ========== Ordering ==========
+
+impl<const D: u32> PartialOrd for SignedDecimal<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const D: u32> Ord for SignedDecimal<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+// ========== Type Conversions ==========
+
+/// From Decimal<D> (always non-negative, never fails)
+impl<const D: u32> From<Decimal<D>> for SignedDecimal<D> {
+    fn from(value: Decimal<D>) -> Self {
+        Self::positive(value)
+    }
+}
+
+/// Try to convert to Decimal<D>, failing for negative values
+impl<const D: u32> TryFrom<SignedDecimal<D>> for Decimal<D> {
+    type Error = CustomDecimalError;
+
+    fn try_from(value: SignedDecimal<D>) -> Result<Self, Self::Error> {
+        if value.negative {
+            return Err(CustomDecimalError::ConversionError(
+                "negative value cannot be converted to Decimal".to_string(),
+            ));
+        }
+        Ok(value.magnitude)
+    }
+}
+
+// ========== Display & FromStr ==========
+
+impl<const D: u32> fmt::Display for SignedDecimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = crate::format::display_digits(self.magnitude, f.precision());
+        let zeros = crate::format::excess_zeros(f.precision());
+        crate::format::pad_digits(f, !self.negative, &digits, digits.len(), zeros)
+    }
+}
+
+impl<const D: u32> fmt::Debug for SignedDecimal<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SignedDecimal<{}>({})", D, self)
+    }
+}
+
+impl<const D: u32> FromStr for SignedDecimal<D> {
+    type Err = CustomDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, magnitude) = split_sign(s)?;
+        Ok(Self::from_parts(Decimal::from_str(magnitude)?, negative))
+    }
+}
+
+/// Split off a single leading `-`. A second sign after it, as in `"-+1"` or
+/// `"--1"`, is rejected rather than handed to the unsigned parser.
+pub(crate) fn split_sign(s: &str) -> Result<(bool, &str), CustomDecimalError> {
+    match s.strip_prefix('-') {
+        Some(magnitude) if magnitude.starts_with(['+', '-']) => {
+            Err(CustomDecimalError::ParseError(format!("Invalid sign: {}", s)))
+        }
+        Some(magnitude) => Ok((true, magnitude)),
+        None => Ok((false, s)),
+    }
+}
+
+// ========== Schema ==========
+
+/// The `Decimal<D>` schema with an optional leading `-`, named per precision.
+impl<const D: u32> JsonSchema for SignedDecimal<D> {
+    fn schema_name() -> String {
+        format!("SignedDecimal{}", D)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Owned(format!("cosmwasm_custom_decimal::SignedDecimal<{}>", D))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("decimal".to_string()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(format!(
+                    "A signed fixed-point decimal with {} decimal places, serialized as a \
+                     string with an optional leading '-' (e.g. \"-1.5\")",
+                    D
+                )),
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^-?[0-9]+(\\.[0-9]{1,18})?$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sd(s: &str) -> SignedDecimal<6> {
+        SignedDecimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(SignedDecimal::<6>::ZERO.magnitude(), Decimal::<6>::ZERO);
+        assert_eq!(SignedDecimal::<6>::ONE.magnitude(), Decimal::<6>::ONE);
+        assert!(SignedDecimal::<6>::NEGATIVE_ONE.is_negative());
+        assert_eq!(SignedDecimal::<6>::MIN, -SignedDecimal::<6>::MAX);
+        assert_eq!(SignedDecimal::<6>::DECIMAL_PLACES, 6);
+        assert_eq!(SignedDecimal::<6>::FRACTIONAL, 1_000_000);
+    }
+
+    #[test]
+    fn test_zero_is_never_negative() {
+        assert_eq!(SignedDecimal::from_parts(Decimal::<6>::ZERO, true), SignedDecimal::ZERO);
+        assert_eq!(sd("-0"), SignedDecimal::ZERO);
+        assert!(!sd("-0.0").is_negative());
+        assert_eq!(-SignedDecimal::<6>::ZERO, SignedDecimal::ZERO);
+        assert_eq!(sd("-1.5") + sd("1.5"), SignedDecimal::ZERO);
+        assert!(!(sd("-1.5") + sd("1.5")).is_negative());
+    }
+
+    #[test]
+    fn test_hash_treats_negative_zero_as_zero() {
+        use std::collections::HashSet;
+
+        let values: HashSet<SignedDecimal<6>> =
+            ["-0", "0", "1.5", "-1.5", "1.50"].iter().map(|s| sd(s)).collect();
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn test_raw() {
+        let d = SignedDecimal::<6>::raw(-1_500_000);
+        assert_eq!(d, sd("-1.5"));
+        assert_eq!(SignedDecimal::<6>::raw(i128::MIN).magnitude().atomics(), 1u128 << 127);
+    }
+
+    #[test]
+    fn test_from_signed_atomics() {
+        assert_eq!(SignedDecimal::<6>::from_signed_atomics(15, 1), Ok(sd("1.5")));
+        assert_eq!(SignedDecimal::<6>::from_signed_atomics(-1_234_567_890, 9), Ok(sd("-1.234567")));
+        assert_eq!(SignedDecimal::<6>::from_signed_atomics(-1, 9), Ok(SignedDecimal::ZERO));
+        assert!(!SignedDecimal::<6>::from_signed_atomics(-1, 9).unwrap().is_negative());
+        assert_eq!(
+            SignedDecimal::<6>::from_signed_atomics(i128::MIN, 0),
+            Err(CustomDecimalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_abs_signum() {
+        assert_eq!(sd("-2.5").abs(), sd("2.5"));
+        assert_eq!(sd("2.5").abs(), sd("2.5"));
+        assert_eq!(SignedDecimal::<6>::MIN.abs(), SignedDecimal::MAX);
+
+        assert_eq!(sd("-2.5").signum(), SignedDecimal::NEGATIVE_ONE);
+        assert_eq!(sd("0").signum(), SignedDecimal::ZERO);
+        assert_eq!(sd("0.000001").signum(), SignedDecimal::ONE);
+
+        assert!(sd("1").is_positive());
+        assert!(!sd("0").is_positive());
+        assert!(!sd("-1").is_positive());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut values = vec![sd("1.5"), sd("-3"), sd("0"), sd("-0.5"), sd("2")];
+        values.sort();
+        assert_eq!(values, vec![sd("-3"), sd("-0.5"), sd("0"), sd("1.5"), sd("2")]);
+        assert!(SignedDecimal::<6>::MIN < SignedDecimal::<6>::MAX);
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        assert_eq!(sd("1.5").checked_add(sd("-4")), Some(sd("-2.5")));
+        assert_eq!(sd("-1.5").checked_add(sd("-4")), Some(sd("-5.5")));
+        assert_eq!(sd("1.5").checked_sub(sd("4")), Some(sd("-2.5")));
+        assert_eq!(sd("-1.5").checked_sub(sd("-4")), Some(sd("2.5")));
+        assert_eq!(sd("-1.5").checked_mul(sd("2")), Some(sd("-3")));
+        assert_eq!(sd("-1.5").checked_mul(sd("-2")), Some(sd("3")));
+        assert_eq!(sd("-3").checked_div(sd("2")), Some(sd("-1.5")));
+        assert_eq!(sd("-7").checked_rem(sd("2")), Some(sd("-1")));
+        assert_eq!(sd("7").checked_rem(sd("-2")), Some(sd("1")));
+
+        assert_eq!(SignedDecimal::<6>::MAX.checked_add(SignedDecimal::ONE), None);
+        assert_eq!(SignedDecimal::<6>::MIN.checked_sub(SignedDecimal::ONE), None);
+        assert_eq!(sd("1").checked_div(SignedDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_division_truncates_toward_zero() {
+        assert_eq!(sd("-1").checked_div(sd("3")), Some(sd("-0.333333")));
+        assert_eq!(sd("1").checked_div(sd("-3")), Some(sd("-0.333333")));
+    }
+
+    #[test]
+    fn test_euclid_matches_integer_semantics() {
+        for (a, b) in [(7i32, 2i32), (-7, 2), (7, -2), (-7, -2), (-6, 3), (0, -5)] {
+            let (da, db) = (sd(&a.to_string()), sd(&b.to_string()));
+            let q = da.div_euclid(db);
+            let r = da.rem_euclid(db);
+            assert_eq!(q, sd(&a.div_euclid(b).to_string()), "{a} div_euclid {b}");
+            assert_eq!(r, sd(&a.rem_euclid(b).to_string()), "{a} rem_euclid {b}");
+            assert_eq!(q * db + r, da);
+        }
+
+        // Fractional operands
+        let (a, b) = (sd("-7.5"), sd("2"));
+        assert_eq!(a.div_euclid(b), sd("-4"));
+        assert_eq!(a.rem_euclid(b), sd("0.5"));
+        assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+
+        assert_eq!(sd("1").checked_div_euclid(SignedDecimal::ZERO), None);
+        assert_eq!(sd("-1").checked_rem_euclid(SignedDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_saturating_operations() {
+        assert_eq!(SignedDecimal::<6>::MAX.saturating_add(SignedDecimal::ONE), SignedDecimal::MAX);
+        assert_eq!(SignedDecimal::<6>::MIN.saturating_sub(SignedDecimal::ONE), SignedDecimal::MIN);
+        assert_eq!(SignedDecimal::<6>::MAX.saturating_mul(sd("-2")), SignedDecimal::MIN);
+        assert_eq!(SignedDecimal::<6>::MIN.saturating_mul(sd("-2")), SignedDecimal::MAX);
+    }
+
+    #[test]
+    fn test_decimal_conversions() {
+        let d = Decimal::<6>::from_str("1.5").unwrap();
+        let signed: SignedDecimal<6> = d.into();
+        assert_eq!(signed, sd("1.5"));
+
+        let back: Decimal<6> = signed.try_into().unwrap();
+        assert_eq!(back, d);
+
+        let max: SignedDecimal<6> = Decimal::<6>::MAX.into();
+        assert_eq!(max, SignedDecimal::MAX);
+
+        let result: Result<Decimal<6>, _> = sd("-1.5").try_into();
+        assert!(matches!(result, Err(CustomDecimalError::ConversionError(_))));
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        assert_eq!(sd("-1.5").to_string(), "-1.5");
+        assert_eq!(sd("1.5").to_string(), "1.5");
+        assert_eq!(sd("-0.000001").to_string(), "-0.000001");
+        assert_eq!(format!("{:?}", sd("-2")), "SignedDecimal<6>(-2)");
+
+        for s in ["-1.5", "0", "123.456", "-0.000001"] {
+            assert_eq!(sd(s).to_string().parse::<SignedDecimal<6>>().unwrap(), sd(s));
+        }
+
+        assert!(SignedDecimal::<6>::from_str("--1").is_err());
+        assert!(SignedDecimal::<6>::from_str("-+1").is_err());
+        assert!(SignedDecimal::<6>::from_str("+-1").is_err());
+        assert!(SignedDecimal::<6>::from_str("++1").is_err());
+        assert_eq!(SignedDecimal::<6>::from_str("+1").unwrap(), SignedDecimal::ONE);
+        assert!(SignedDecimal::<6>::from_str("-").is_err());
+        assert!(SignedDecimal::<6>::from_str("1-").is_err());
+    }
+
+    #[test]
+    fn test_display_flags() {
+        assert_eq!(format!("{:.2}", sd("-1.5")), "-1.50");
+        assert_eq!(format!("{:>7}", sd("-1.5")), "   -1.5");
+        assert_eq!(format!("{:07.2}", sd("-1.5")), "-001.50");
+        assert_eq!(format!("{:+}", sd("1.5")), "+1.5");
+    }
+}
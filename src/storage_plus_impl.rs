@@ -0,0 +1,86 @@
+use crate::Decimal;
+use alloc::vec;
+use alloc::vec::Vec;
+use cosmwasm_std::{StdError, StdResult};
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
+
+/// A Decimal<D> key is its `u128` atomics as 16 big-endian bytes, so the
+/// lexical order of stored keys matches numeric order.
+///
+/// The precision is not part of the key; a map should use a single `D`.
+impl<'a, const D: u32> PrimaryKey<'a> for Decimal<D> {
+    type Prefix = ();
+    type SubPrefix = ();
+    type Suffix = Self;
+    type SuperSuffix = Self;
+
+    fn key(&self) -> Vec<Key<'_>> {
+        vec![Key::Val128(self.0.u128().to_be_bytes())]
+    }
+}
+
+impl<'a, const D: u32> Prefixer<'a> for Decimal<D> {
+    fn prefix(&self) -> Vec<Key<'_>> {
+        vec![Key::Val128(self.0.u128().to_be_bytes())]
+    }
+}
+
+impl<const D: u32> KeyDeserialize for Decimal<D> {
+    type Output = Self;
+
+    const KEY_ELEMS: u16 = 1;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        let bytes: [u8; 16] = value
+            .as_slice()
+            .try_into()
+            .map_err(|_| StdError::generic_err("Decimal key must be 16 bytes"))?;
+        Ok(Self::raw(u128::from_be_bytes(bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decimal6;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Order;
+    use cw_storage_plus::Map;
+    use std::str::FromStr;
+
+    const LEVELS: Map<Decimal6, u64> = Map::new("levels");
+
+    #[test]
+    fn test_map_iterates_in_numeric_order() {
+        let mut storage = MockStorage::new();
+        let prices = ["10.5", "0.000001", "2", "256", "1.999999", "0"];
+        for (i, price) in prices.iter().enumerate() {
+            let price = Decimal6::from_str(price).unwrap();
+            LEVELS.save(&mut storage, price, &(i as u64)).unwrap();
+        }
+        LEVELS.save(&mut storage, Decimal6::MAX, &99).unwrap();
+
+        let keys: Vec<Decimal6> = LEVELS
+            .keys(&storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        let mut expected: Vec<Decimal6> =
+            prices.iter().map(|p| Decimal6::from_str(p).unwrap()).collect();
+        expected.push(Decimal6::MAX);
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        let price = Decimal6::from_str("1.999999").unwrap();
+        assert_eq!(LEVELS.load(&storage, price).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_key_roundtrip() {
+        for d in [Decimal6::ZERO, Decimal6::ONE, Decimal6::MAX, Decimal6::raw(12_345)] {
+            let bytes = d.joined_key();
+            assert_eq!(bytes, d.0.u128().to_be_bytes());
+            assert_eq!(Decimal6::from_slice(&bytes).unwrap(), d);
+        }
+        assert!(Decimal6::from_slice(&[0u8; 8]).is_err());
+    }
+}
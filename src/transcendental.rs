@@ -0,0 +1,841 @@
+use crate::rounding::{div_rounded, RoundingMode};
+use crate::{pow10, Decimal, SignedDecimal};
+use cosmwasm_std::{Uint128, Uint256, Uint512};
+
+// ========== Fixed-Point Engine ==========
+//
+// Logarithms are evaluated on `Uint256` values with PRECISION fractional digits
+// and only rounded to `D` places at the end, so the `D`-digit result carries
+// the full precision of the series.
+
+/// Fractional digits carried by the internal arithmetic
+const PRECISION: u32 = 38;
+
+/// 1.0 at internal precision
+const SCALE: Uint256 = Uint256::from_u128(pow10(PRECISION));
+
+/// ln(2) at internal precision, rounded to nearest
+const LN_2: Uint256 = Uint256::from_u128(69314718055994530941723212145817656808);
+
+/// ln(10) at internal precision, rounded to nearest
+const LN_10: Uint256 = Uint256::from_u128(230258509299404568401799145468436420760);
+
+/// ln(atomics / 10^decimals) at internal precision as `(negative, magnitude)`.
+/// `atomics` must be nonzero.
+fn ln_internal(atomics: u128, decimals: u32) -> (bool, Uint256) {
+    // x = m * 2^k with m in [1, 2). Normalizing x itself (rather than the
+    // atomics) keeps ln(1) exactly zero and avoids cancelling a D * ln(10) term.
+    let mut m = Uint256::from(atomics) * Uint256::from(pow10(PRECISION - decimals));
+    let mut k: i32 = 0;
+    while m >= SCALE * Uint256::from(2u8) {
+        m >>= 1;
+        k += 1;
+    }
+    while m < SCALE {
+        m <<= 1;
+        k -= 1;
+    }
+
+    // ln(m) = 2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...) with z = (m - 1) / (m + 1) < 1/3
+    let z = (m - SCALE) * SCALE / (m + SCALE);
+    let z_squared = z * z / SCALE;
+    let mut term = z;
+    let mut series = Uint256::zero();
+    let mut n = 1u32;
+    while !term.is_zero() {
+        series += term / Uint256::from(n);
+        term = term * z_squared / SCALE;
+        n += 2;
+    }
+    let ln_m = series * Uint256::from(2u8);
+
+    let ln_2k = LN_2 * Uint256::from(k.unsigned_abs());
+    if k >= 0 {
+        (false, ln_2k + ln_m)
+    } else if ln_2k >= ln_m {
+        (true, ln_2k - ln_m)
+    } else {
+        (false, ln_m - ln_2k)
+    }
+}
+
+/// `a / b` at internal precision without overflowing the intermediate product
+fn div_internal(a: Uint256, b: Uint256) -> Uint256 {
+    (a / b) * SCALE + (a % b) * SCALE / b
+}
+
+/// Round an internal-precision magnitude to `D` places. Returns `None` if it
+/// exceeds `Decimal::<D>::MAX`.
+fn to_decimal<const D: u32>(magnitude: Uint256) -> Option<Decimal<D>> {
+    let divisor = Uint256::from(pow10(PRECISION - D));
+    let atomics = div_rounded(magnitude, divisor, RoundingMode::HalfUp);
+    Uint128::try_from(atomics).ok().map(Decimal)
+}
+
+/// Logarithm of a positive signed value in the given base (`None` for natural)
+fn log_signed<const D: u32>(
+    value: SignedDecimal<D>,
+    base_ln: Option<Uint256>,
+) -> Option<SignedDecimal<D>> {
+    if !value.is_positive() {
+        return None;
+    }
+
+    let (negative, ln) = ln_internal(value.magnitude.0.u128(), D);
+    let magnitude = match base_ln {
+        Some(base_ln) => div_internal(ln, base_ln),
+        None => ln,
+    };
+    Some(SignedDecimal::from_parts(to_decimal(magnitude)?, negative))
+}
+
+/// Largest input for which `e^x` can fit in a `u128`: ln(2^128) < 89
+const EXP_INPUT_LIMIT: u128 = 89;
+
+/// e^r at internal precision for `0 <= r < ln(2)`, by Taylor series
+fn exp_reduced(r: Uint256) -> Uint256 {
+    let mut term = SCALE;
+    let mut sum = SCALE;
+    let mut n = 1u32;
+    while !term.is_zero() {
+        term = term * r / (SCALE * Uint256::from(n));
+        sum += term;
+        n += 1;
+    }
+    sum
+}
+
+/// e^x rounded to `D` places per `mode` for `x` at internal precision. Returns
+/// `None` if the result exceeds `Decimal::<D>::MAX`.
+fn exp_internal<const D: u32>(negative: bool, x: Uint256, mode: RoundingMode) -> Option<Decimal<D>> {
+    let unit = Uint256::from(pow10(PRECISION - D));
+
+    // x = k * ln(2) + r with 0 <= r < ln(2), so e^x = e^r * 2^k
+    if !negative {
+        if x > Uint256::from(EXP_INPUT_LIMIT) * SCALE {
+            return None;
+        }
+        // k <= 128 and e^r < 2^128, so the shift stays within 256 bits
+        let k = Uint128::try_from(x / LN_2).ok()?.u128() as u32;
+        let r = x - LN_2 * Uint256::from(k);
+        let atomics = div_rounded(exp_reduced(r) << k, unit, mode);
+        return Uint128::try_from(atomics).ok().map(Decimal);
+    }
+
+    // e^-x = e^r / 2^k with k = floor(x / ln(2)) + 1. Past k = 128 the result is
+    // below 10^-38 / 2 and rounds to zero at every precision.
+    let k = x / LN_2 + Uint256::one();
+    if k > Uint256::from(128u8) {
+        return Some(Decimal::ZERO);
+    }
+    let k = Uint128::try_from(k).ok()?.u128() as u32;
+    let r = LN_2 * Uint256::from(k) - x;
+    // r may reach ln(2) when x is a multiple of ln(2); the series still converges
+    let atomics = div_rounded(exp_reduced(r), unit << k, mode);
+    Some(Decimal(Uint128::try_from(atomics).ok()?))
+}
+
+/// `base^exp` as `e^(exp * ln(base))` for a positive base. `exp_negative` is the
+/// sign of the exponent.
+fn powf_internal<const D: u32>(
+    base: Decimal<D>,
+    exp: Decimal<D>,
+    exp_negative: bool,
+) -> Option<Decimal<D>> {
+    let (ln_negative, ln) = ln_internal(base.0.u128(), D);
+    let negative = ln_negative != exp_negative;
+    match ln.checked_mul(to_internal(exp)) {
+        Ok(product) => exp_internal(negative, product / SCALE, RoundingMode::HalfUp),
+        // The exponent is astronomically large: e^-inf is zero, e^+inf overflows
+        Err(_) if negative => Some(Decimal::ZERO),
+        Err(_) => None,
+    }
+}
+
+/// `a * b` at `D` places, or `None` if the product is inexact or overflows
+fn mul_exact<const D: u32>(a: Decimal<D>, b: Decimal<D>) -> Option<Decimal<D>> {
+    let product = Uint256::from(a.0) * Uint256::from(b.0);
+    let fractional = Uint256::from(Decimal::<D>::FRACTIONAL);
+    if !(product % fractional).is_zero() {
+        return None;
+    }
+    Uint128::try_from(product / fractional).ok().map(Decimal)
+}
+
+/// `base^exp` without truncation, or `None` if any step truncates or overflows.
+///
+/// If the exact power is representable then so is every intermediate power
+/// `base^j` with `j <= exp`, so `None` means `base^exp` is not exactly a `D`-place value.
+fn pow_exact<const D: u32>(base: Decimal<D>, exp: u32) -> Option<Decimal<D>> {
+    let mut result = Decimal::ONE;
+    let mut base = base;
+    let mut remaining = exp;
+    loop {
+        if remaining & 1 == 1 {
+            result = mul_exact(result, base)?;
+        }
+        remaining >>= 1;
+        if remaining == 0 {
+            return Some(result);
+        }
+        base = mul_exact(base, base)?;
+    }
+}
+
+/// `value` at internal precision
+fn to_internal<const D: u32>(value: Decimal<D>) -> Uint256 {
+    Uint256::from(value.0) * Uint256::from(pow10(PRECISION - D))
+}
+
+// ========== Logarithms ==========
+
+/// Logarithms are computed with 38 fractional digits internally and rounded to
+/// nearest at `D` places. The internal error is below `10^-36`, so results are
+/// within one unit in the last place for every `D <= 35`.
+///
+/// `Decimal<D>` is unsigned, so the logarithm of a value below one (which is
+/// negative) is not representable. Use [`SignedDecimal`] for those inputs.
+impl<const D: u32> Decimal<D> {
+    /// Checked natural logarithm. Returns `None` for zero and for values below one.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal18;
+    /// use std::str::FromStr;
+    ///
+    /// let two = Decimal18::from_str("2").unwrap();
+    /// assert_eq!(two.checked_ln(), Some(Decimal18::from_str("0.693147180559945309").unwrap()));
+    /// assert_eq!(Decimal18::from_str("0.5").unwrap().checked_ln(), None);
+    /// ```
+    pub fn checked_ln(self) -> Option<Self> {
+        SignedDecimal::from(self).checked_ln()?.try_into().ok()
+    }
+
+    /// Checked base-10 logarithm. Returns `None` for zero and for values below one.
+    pub fn checked_log10(self) -> Option<Self> {
+        SignedDecimal::from(self).checked_log10()?.try_into().ok()
+    }
+
+    /// Checked base-2 logarithm. Returns `None` for zero and for values below one.
+    pub fn checked_log2(self) -> Option<Self> {
+        SignedDecimal::from(self).checked_log2()?.try_into().ok()
+    }
+
+    /// Natural logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or below one.
+    pub fn ln(self) -> Self {
+        self.checked_ln().expect("logarithm of a value below one")
+    }
+
+    /// Base-10 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or below one.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal18;
+    /// let d = Decimal18::from(1000u64);
+    /// assert_eq!(d.log10(), Decimal18::from(3u64));
+    /// ```
+    pub fn log10(self) -> Self {
+        self.checked_log10().expect("logarithm of a value below one")
+    }
+
+    /// Base-2 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or below one.
+    pub fn log2(self) -> Self {
+        self.checked_log2().expect("logarithm of a value below one")
+    }
+}
+
+/// Same accuracy as the `Decimal<D>` logarithms, with negative results for
+/// inputs below one.
+impl<const D: u32> SignedDecimal<D> {
+    /// Checked natural logarithm. Returns `None` for zero and negative values, or
+    /// if the result does not fit (only possible for `D > 36`).
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// use std::str::FromStr;
+    ///
+    /// let half = SignedDecimal::<18>::from_str("0.5").unwrap();
+    /// assert_eq!(half.checked_ln().unwrap().to_string(), "-0.693147180559945309");
+    /// ```
+    pub fn checked_ln(self) -> Option<Self> {
+        log_signed(self, None)
+    }
+
+    /// Checked base-10 logarithm. Returns `None` for zero and negative values.
+    pub fn checked_log10(self) -> Option<Self> {
+        log_signed(self, Some(LN_10))
+    }
+
+    /// Checked base-2 logarithm. Returns `None` for zero and negative values.
+    pub fn checked_log2(self) -> Option<Self> {
+        log_signed(self, Some(LN_2))
+    }
+
+    /// Natural logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative.
+    pub fn ln(self) -> Self {
+        self.checked_ln().expect("logarithm of a non-positive value")
+    }
+
+    /// Base-10 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative.
+    pub fn log10(self) -> Self {
+        self.checked_log10().expect("logarithm of a non-positive value")
+    }
+
+    /// Base-2 logarithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or negative.
+    pub fn log2(self) -> Self {
+        self.checked_log2().expect("logarithm of a non-positive value")
+    }
+}
+
+// ========== Exponential ==========
+
+/// `e^x` reduces the argument to `r < ln(2)`, sums the Taylor series with 38
+/// fractional digits and rounds to nearest at `D` places. The relative error is
+/// below `10^-36`: for `Decimal<18>` results are within one unit in the last
+/// place up to `10^17`.
+impl<const D: u32> Decimal<D> {
+    /// Checked exponential `e^self`. Returns `None` if the result overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal18;
+    /// use std::str::FromStr;
+    ///
+    /// let e = Decimal18::ONE.checked_exp().unwrap();
+    /// assert_eq!(e, Decimal18::from_str("2.718281828459045235").unwrap());
+    /// assert_eq!(Decimal18::from(200u64).checked_exp(), None);
+    /// ```
+    pub fn checked_exp(self) -> Option<Self> {
+        exp_internal(false, to_internal(self), RoundingMode::HalfUp)
+    }
+
+    /// Exponential `e^self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    pub fn exp(self) -> Self {
+        self.checked_exp().expect("overflow in exp")
+    }
+}
+
+impl<const D: u32> SignedDecimal<D> {
+    /// Checked exponential `e^self`. Returns `None` if the result overflows.
+    /// Negative inputs give results between zero and one.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// use std::str::FromStr;
+    ///
+    /// let d = SignedDecimal::<18>::NEGATIVE_ONE.checked_exp().unwrap();
+    /// assert_eq!(d.to_string(), "0.367879441171442322");
+    /// ```
+    pub fn checked_exp(self) -> Option<Self> {
+        exp_internal(self.negative, to_internal(self.magnitude), RoundingMode::HalfUp)
+            .map(Self::from)
+    }
+
+    /// Exponential `e^self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    pub fn exp(self) -> Self {
+        self.checked_exp().expect("overflow in exp")
+    }
+}
+
+// ========== Powers and Roots ==========
+
+/// Fractional powers are evaluated as `e^(exp * ln(base))` on the internal
+/// 38-digit values, so only the final result is rounded (to nearest). The
+/// relative error grows with the exponent, roughly `exp * 10^-36`.
+impl<const D: u32> Decimal<D> {
+    /// Checked fractional power `self^exp`. Returns `None` on overflow.
+    ///
+    /// `0^0` is `ONE` and `0^exp` is `ZERO` for any positive `exp`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal18;
+    /// use std::str::FromStr;
+    ///
+    /// let four = Decimal18::from(4u64);
+    /// let half = Decimal18::from_str("0.5").unwrap();
+    /// assert_eq!(four.checked_powf(half), Some(Decimal18::from(2u64)));
+    /// ```
+    pub fn checked_powf(self, exp: Self) -> Option<Self> {
+        if exp.is_zero() {
+            return Some(Self::ONE);
+        }
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+        powf_internal(self, exp, false)
+    }
+
+    /// Fractional power `self^exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    pub fn powf(self, exp: Self) -> Self {
+        self.checked_powf(exp).expect("overflow in powf")
+    }
+
+    /// Checked `n`th root, truncated to `D` places like [`Decimal::sqrt`].
+    /// Returns `None` for `n == 0`.
+    ///
+    /// Exact roots are returned exactly: `8.nth_root(3)` is `2`, not `1.999...`.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    ///
+    /// let d = Decimal6::from_str("15.625").unwrap();
+    /// assert_eq!(d.checked_nth_root(3), Some(Decimal6::from_str("2.5").unwrap()));
+    /// assert_eq!(d.checked_nth_root(0), None);
+    /// ```
+    pub fn checked_nth_root(self, n: u32) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        if n == 1 || self.is_zero() {
+            return Some(self);
+        }
+
+        // Roots never exceed max(self, 1), so this cannot overflow
+        let (negative, ln) = ln_internal(self.0.u128(), D);
+        let root: Self = exp_internal(negative, ln / Uint256::from(n), RoundingMode::Truncate)?;
+
+        // The estimate may land one unit below an exact root
+        let next = Self(root.0 + Uint128::one());
+        if pow_exact(next, n) == Some(self) {
+            return Some(next);
+        }
+        Some(root)
+    }
+
+    /// `n`th root, truncated to `D` places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    pub fn nth_root(self, n: u32) -> Self {
+        self.checked_nth_root(n).expect("zeroth root is undefined")
+    }
+
+    // ========== Means ==========
+
+    /// Geometric mean, the `n`th root of the product of `n` values.
+    ///
+    /// Returns `None` for an empty slice or if any value is zero.
+    ///
+    /// The logarithms are averaged at 38 fractional digits and exponentiated
+    /// once, so the product is never formed and cannot overflow. The result is
+    /// rounded to nearest and lies within one unit in the last place for every
+    /// `D <= 35`; a single value is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    ///
+    /// let values = [Decimal6::from(4u64), Decimal6::from(9u64)];
+    /// assert_eq!(Decimal6::geometric_mean(&values), Some(Decimal6::from(6u64)));
+    /// assert_eq!(Decimal6::geometric_mean(&[]), None);
+    /// ```
+    pub fn geometric_mean(values: &[Self]) -> Option<Self> {
+        if values.is_empty() || values.iter().any(Self::is_zero) {
+            return None;
+        }
+        if let [value] = values {
+            return Some(*value);
+        }
+
+        // Sum the logarithms as separate positive and negative parts
+        let (mut positive, mut negative) = (Uint256::zero(), Uint256::zero());
+        for value in values {
+            match ln_internal(value.0.u128(), D) {
+                (false, ln) => positive += ln,
+                (true, ln) => negative += ln,
+            }
+        }
+        let n = Uint256::from(values.len() as u128);
+        if positive >= negative {
+            exp_internal(false, (positive - negative) / n, RoundingMode::HalfUp)
+        } else {
+            exp_internal(true, (negative - positive) / n, RoundingMode::HalfUp)
+        }
+    }
+
+    /// Harmonic mean, `n / sum(1 / v_i)`, e.g. the average price of buying a
+    /// fixed amount of value at each of `n` prices.
+    ///
+    /// Returns `None` for an empty slice or if any value is zero.
+    ///
+    /// The reciprocals are taken at 115 fractional digits of the atomics and
+    /// summed in `Uint512`, so no step can overflow and their rounding is far
+    /// below one unit of the result. The result is rounded down and lies
+    /// within one unit in the last place; like the exact value it never
+    /// exceeds the geometric or arithmetic mean of the same values.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::Decimal6;
+    /// use std::str::FromStr;
+    ///
+    /// let values = [Decimal6::ONE, Decimal6::from(4u64)];
+    /// assert_eq!(Decimal6::harmonic_mean(&values), Some(Decimal6::from_str("1.6").unwrap()));
+    /// assert_eq!(Decimal6::harmonic_mean(&[Decimal6::ONE, Decimal6::ZERO]), None);
+    /// ```
+    pub fn harmonic_mean(values: &[Self]) -> Option<Self> {
+        if values.is_empty() || values.iter().any(Self::is_zero) {
+            return None;
+        }
+
+        // 10^115 * n fits in Uint512 for any slice length
+        let scale = Uint512::from(10u8).pow(115);
+        let mut reciprocals = Uint512::zero();
+        for value in values {
+            reciprocals += scale / Uint512::from(value.0.u128());
+        }
+        let n = Uint512::from(values.len() as u128);
+
+        // The mean never exceeds the largest value
+        Uint128::try_from(n * scale / reciprocals).ok().map(Self)
+    }
+}
+
+impl<const D: u32> SignedDecimal<D> {
+    /// Checked fractional power `self^exp`, allowing negative exponents.
+    ///
+    /// Returns `None` for a negative base, for zero raised to a negative power,
+    /// and on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use cosmwasm_custom_decimal::SignedDecimal;
+    /// use std::str::FromStr;
+    ///
+    /// let four = SignedDecimal::<18>::from_str("4").unwrap();
+    /// let exp = SignedDecimal::<18>::from_str("-0.5").unwrap();
+    /// assert_eq!(four.checked_powf(exp).unwrap().to_string(), "0.5");
+    /// assert_eq!((-four).checked_powf(exp), None);
+    /// ```
+    pub fn checked_powf(self, exp: Self) -> Option<Self> {
+        if self.negative {
+            return None;
+        }
+        if exp.is_zero() {
+            return Some(Self::ONE);
+        }
+        if self.is_zero() {
+            return if exp.negative { None } else { Some(Self::ZERO) };
+        }
+        powf_internal(self.magnitude, exp.magnitude, exp.negative).map(Self::from)
+    }
+
+    /// Fractional power `self^exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is negative, for zero raised to a negative power, and
+    /// on overflow.
+    pub fn powf(self, exp: Self) -> Self {
+        self.checked_powf(exp).expect("powf is undefined or overflows")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decimal18, Decimal6};
+    use std::str::FromStr;
+
+    fn d18(s: &str) -> Decimal18 {
+        Decimal18::from_str(s).unwrap()
+    }
+
+    fn sd18(s: &str) -> SignedDecimal<18> {
+        SignedDecimal::from_str(s).unwrap()
+    }
+
+    fn assert_within_ulp(actual: Decimal18, expected: Decimal18) {
+        assert!(
+            actual.abs_diff(expected) <= Decimal18::raw(1),
+            "{} is not within one ulp of {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_internal_precision() {
+        // ln(3) and ln(1.9) to 38 places; the errors stay far below 10^-36
+        let (negative, ln_3) = ln_internal(3, 0);
+        assert!(!negative);
+        let expected = Uint256::from(109861228866810969139524523692252570465u128);
+        assert!(ln_3.abs_diff(expected) <= Uint256::from(100u8));
+
+        let (negative, ln_1_9) = ln_internal(19, 1);
+        assert!(!negative);
+        let expected = Uint256::from(64185388617239477599103597720348932964u128);
+        assert!(ln_1_9.abs_diff(expected) <= Uint256::from(100u8));
+    }
+
+    #[test]
+    fn test_ln() {
+        // e truncated to 18 places; ln is 1 - 1.3e-19
+        assert_within_ulp(d18("2.718281828459045235").ln(), Decimal18::ONE);
+        assert_eq!(d18("2").ln(), d18("0.693147180559945309"));
+        assert_eq!(d18("1000").ln(), d18("6.907755278982137052"));
+        assert_eq!(d18("123.456").ln(), d18("4.815884817283263883"));
+        assert_eq!(Decimal18::MAX.ln(), d18("47.276307437780177293"));
+        assert_eq!(Decimal18::ONE.ln(), Decimal18::ZERO);
+    }
+
+    #[test]
+    fn test_log10_log2() {
+        assert_eq!(d18("1000").log10(), d18("3"));
+        assert_eq!(d18("2").log10(), d18("0.301029995663981195"));
+        assert_eq!(d18("123.456").log10(), d18("2.091512201627771681"));
+        assert_eq!(Decimal18::MAX.log10(), d18("20.531839444989592987"));
+
+        assert_eq!(d18("2").log2(), Decimal18::ONE);
+        assert_eq!(d18("1024").log2(), d18("10"));
+        assert_eq!(d18("1000").log2(), d18("9.965784284662087044"));
+        assert_eq!(Decimal18::MAX.log2(), d18("68.205294292027477738"));
+    }
+
+    #[test]
+    fn test_low_precision() {
+        assert_eq!(Decimal6::from(1000u64).log10(), Decimal6::from(3u64));
+        assert_eq!(Decimal6::from(2u64).ln(), Decimal6::from_str("0.693147").unwrap());
+        assert_eq!(Decimal::<0>::from(100u64).log10(), Decimal::<0>::from(2u64));
+    }
+
+    #[test]
+    fn test_unrepresentable_inputs() {
+        assert_eq!(Decimal18::ZERO.checked_ln(), None);
+        assert_eq!(Decimal18::ZERO.checked_log10(), None);
+        assert_eq!(d18("0.5").checked_log2(), None);
+        assert_eq!(Decimal18::ONE.checked_log2(), Some(Decimal18::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "logarithm of a value below one")]
+    fn test_ln_below_one_panics() {
+        let _ = d18("0.999").ln();
+    }
+
+    #[test]
+    fn test_signed_logarithms() {
+        assert_eq!(sd18("0.5").ln(), sd18("-0.693147180559945309"));
+        assert_eq!(sd18("0.5").log2(), sd18("-1"));
+        assert_eq!(sd18("0.000000000000000001").ln(), sd18("-41.446531673892822312"));
+        assert_eq!(sd18("0.000000000000000001").log10(), sd18("-18"));
+        assert_eq!(sd18("0.000000000000000001").log2(), sd18("-59.794705707972522262"));
+        assert_eq!(sd18("1000").log10(), sd18("3"));
+
+        assert_eq!(SignedDecimal::<18>::ZERO.checked_ln(), None);
+        assert_eq!(sd18("-2").checked_ln(), None);
+        assert_eq!(sd18("-2").checked_log10(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "logarithm of a non-positive value")]
+    fn test_signed_ln_negative_panics() {
+        let _ = sd18("-1").ln();
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(Decimal18::ZERO.exp(), Decimal18::ONE);
+        assert_eq!(d18("1").exp(), d18("2.718281828459045235"));
+        assert_eq!(d18("0.5").exp(), d18("1.648721270700128147"));
+        assert_eq!(d18("10").exp(), d18("22026.465794806716516958"));
+        assert_eq!(d18("20").exp(), d18("485165195.409790277969106831"));
+
+        // Large results keep a relative error below 10^-36
+        let big = d18("42.5").exp();
+        assert!(big.abs_diff(d18("2867579591680571559.563933018785474169")) <= Decimal18::raw(10));
+    }
+
+    #[test]
+    fn test_exp_overflow() {
+        assert_eq!(d18("200").checked_exp(), None);
+        assert_eq!(d18("48").checked_exp(), None);
+        assert!(d18("47").checked_exp().is_some());
+        assert_eq!(Decimal18::MAX.checked_exp(), None);
+
+        // e^88 fits in u128 only without fractional digits
+        assert!(Decimal::<0>::from(88u64).checked_exp().is_some());
+        assert_eq!(Decimal::<0>::from(89u64).checked_exp(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in exp")]
+    fn test_exp_overflow_panics() {
+        let _ = d18("200").exp();
+    }
+
+    #[test]
+    fn test_exp_inverts_ln() {
+        // ln(x) is rounded to 18 places, which exp amplifies by a factor of x
+        for s in ["1.5", "2", "3", "7.25"] {
+            let x = d18(s);
+            let diff = x.ln().exp().abs_diff(x);
+            assert!(diff <= Decimal18::raw(10), "{} -> {}", s, diff);
+        }
+        assert_eq!(Decimal6::from_str("0.693147").unwrap().exp(), Decimal6::from(2u64));
+    }
+
+    #[test]
+    fn test_signed_exp() {
+        assert_eq!(sd18("-1").exp(), sd18("0.367879441171442322"));
+        assert_eq!(sd18("-41").exp(), sd18("0.000000000000000002"));
+        assert_eq!(sd18("-50").exp(), SignedDecimal::ZERO);
+        assert_eq!(SignedDecimal::<18>::MIN.exp(), SignedDecimal::ZERO);
+        assert_eq!(sd18("1").exp(), sd18("2.718281828459045235"));
+        assert_eq!(sd18("200").checked_exp(), None);
+
+        // e^x * e^-x == 1
+        let product = sd18("3.25").exp() * sd18("-3.25").exp();
+        assert!((product - SignedDecimal::ONE).abs() <= SignedDecimal::raw(100));
+    }
+
+    #[test]
+    fn test_powf() {
+        assert_eq!(d18("4").powf(d18("0.5")), d18("2"));
+        assert_eq!(d18("8").powf(d18("0.333333333333333333")), d18("1.999999999999999999"));
+        assert_eq!(d18("2").powf(d18("10")), d18("1024"));
+        assert_eq!(d18("1.05").powf(d18("2.5")), d18("1.129726321947045722"));
+        assert_eq!(d18("0.25").powf(d18("1.5")), d18("0.125"));
+        assert_eq!(d18("7.5").powf(Decimal18::ONE), d18("7.5"));
+    }
+
+    #[test]
+    fn test_powf_edge_cases() {
+        assert_eq!(Decimal18::ZERO.powf(Decimal18::ZERO), Decimal18::ONE);
+        assert_eq!(Decimal18::ZERO.powf(d18("0.5")), Decimal18::ZERO);
+        assert_eq!(d18("123.4").powf(Decimal18::ZERO), Decimal18::ONE);
+        assert_eq!(Decimal18::ONE.powf(Decimal18::MAX), Decimal18::ONE);
+
+        assert_eq!(d18("2").checked_powf(d18("100")), None);
+        assert_eq!(d18("2").checked_powf(Decimal18::MAX), None);
+        assert_eq!(d18("0.5").checked_powf(Decimal18::MAX), Some(Decimal18::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow in powf")]
+    fn test_powf_overflow_panics() {
+        let _ = d18("10").powf(d18("40"));
+    }
+
+    #[test]
+    fn test_signed_powf() {
+        assert_eq!(sd18("4").powf(sd18("-0.5")), sd18("0.5"));
+        assert_eq!(sd18("0.5").powf(sd18("-2")), sd18("4"));
+        assert_eq!(SignedDecimal::<18>::ZERO.powf(SignedDecimal::ZERO), SignedDecimal::ONE);
+        assert_eq!(SignedDecimal::<18>::ZERO.checked_powf(sd18("-1")), None);
+        assert_eq!(sd18("-4").checked_powf(sd18("0.5")), None);
+        assert_eq!(sd18("-4").checked_powf(SignedDecimal::ZERO), None);
+    }
+
+    #[test]
+    fn test_nth_root() {
+        assert_eq!(d18("8").nth_root(3), d18("2"));
+        assert_eq!(d18("27").nth_root(3), d18("3"));
+        assert_eq!(d18("1024").nth_root(10), d18("2"));
+        assert_eq!(d18("0.001").nth_root(3), d18("0.1"));
+        assert_eq!(d18("2").nth_root(3), d18("1.259921049894873164"));
+        assert_eq!(d18("5").nth_root(1), d18("5"));
+        assert_eq!(Decimal18::ZERO.nth_root(7), Decimal18::ZERO);
+        assert_eq!(Decimal18::MAX.nth_root(2), d18("18446744073.709551615999999999"));
+    }
+
+    #[test]
+    fn test_nth_root_matches_sqrt() {
+        for atomics in (0..200_000u128).step_by(997).chain([1, 2, 4, 10, 1_000_000, u64::MAX as u128]) {
+            let d = Decimal6::raw(atomics);
+            assert_eq!(d.nth_root(2), d.sqrt(), "sqrt({})", d);
+        }
+    }
+
+    #[test]
+    fn test_geometric_mean() {
+        let d6 = |s: &str| Decimal6::from_str(s).unwrap();
+        assert_eq!(Decimal6::geometric_mean(&[d6("4"), d6("9")]), Some(d6("6")));
+        assert_eq!(Decimal18::geometric_mean(&[d18("4"), d18("9")]), Some(d18("6")));
+        assert_eq!(Decimal6::geometric_mean(&[d6("0.5"), d6("2"), d6("8")]), Some(d6("2")));
+        assert_eq!(Decimal6::geometric_mean(&[d6("1.234567")]), Some(d6("1.234567")));
+
+        assert_eq!(Decimal6::geometric_mean(&[]), None);
+        assert_eq!(Decimal6::geometric_mean(&[d6("4"), Decimal6::ZERO]), None);
+
+        // The product of these overflows, but the mean does not
+        let big = [Decimal18::MAX, d18("4")];
+        assert!(Decimal18::MAX.checked_mul(d18("4")).is_none());
+        let mean = Decimal18::geometric_mean(&big).unwrap();
+        // sqrt truncates, so doubling it may be two units low
+        assert!(mean.approx_eq_ulps(Decimal18::MAX.sqrt() * d18("2"), 2));
+        assert_within_ulp(Decimal18::geometric_mean(&[d18("2"), d18("3")]).unwrap(), d18("6").sqrt());
+    }
+
+    #[test]
+    fn test_harmonic_mean() {
+        let d6 = |s: &str| Decimal6::from_str(s).unwrap();
+        assert_eq!(Decimal6::harmonic_mean(&[d6("1"), d6("4")]), Some(d6("1.6")));
+        assert_eq!(Decimal6::harmonic_mean(&[d6("2"), d6("3"), d6("6")]), Some(d6("3")));
+        // 3 / (1 + 1/2 + 1/3) = 1.636363..., rounded down
+        assert_eq!(Decimal6::harmonic_mean(&[d6("1"), d6("2"), d6("3")]), Some(d6("1.636363")));
+        assert_eq!(Decimal6::harmonic_mean(&[d6("1.234567")]), Some(d6("1.234567")));
+
+        assert_eq!(Decimal6::harmonic_mean(&[]), None);
+        assert_eq!(Decimal6::harmonic_mean(&[d6("4"), Decimal6::ZERO]), None);
+
+        // Extremes neither overflow nor drift
+        assert_eq!(Decimal18::harmonic_mean(&[Decimal18::MAX; 3]), Some(Decimal18::MAX));
+        assert_eq!(
+            Decimal18::harmonic_mean(&[Decimal18::EPSILON, Decimal18::MAX]),
+            Some(Decimal18::raw(1))
+        );
+
+        // Harmonic <= geometric for the same values
+        let values = [d6("1.5"), d6("2.25"), d6("10")];
+        assert!(Decimal6::harmonic_mean(&values) <= Decimal6::geometric_mean(&values));
+    }
+
+    #[test]
+    #[should_panic(expected = "zeroth root is undefined")]
+    fn test_zeroth_root_panics() {
+        let _ = d18("8").nth_root(0);
+    }
+}
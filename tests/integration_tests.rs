@@ -9,7 +9,6 @@
 
 use cosmwasm_custom_decimal::{CustomDecimal, Decimal, Decimal6, Decimal9, Decimal12, Decimal18};
 use cosmwasm_std::{Decimal as StdDecimal, Uint128};
-use serde_json;
 use std::str::FromStr;
 
 // ========== Storage Interoperability Tests ==========
@@ -478,6 +477,7 @@ fn test_floor_ceil_edge_cases() {
 // ========== Uint128 Operations Tests ==========
 
 #[test]
+#[allow(clippy::op_ref)]
 fn test_mul_uint128_variations() {
     let dec = CustomDecimal::from_str("2.5").unwrap();
     let uint = Uint128::new(100);
@@ -489,6 +489,7 @@ fn test_mul_uint128_variations() {
 }
 
 #[test]
+#[allow(clippy::op_ref)]
 fn test_div_uint128_variations() {
     let dec = CustomDecimal::from_str("10.0").unwrap();
     let uint = Uint128::new(2);
@@ -513,7 +514,7 @@ fn test_to_uint_rounding() {
 
 #[test]
 fn test_sum_iterator() {
-    let values = vec![
+    let values = [
         CustomDecimal::from_str("1.0").unwrap(),
         CustomDecimal::from_str("2.5").unwrap(),
         CustomDecimal::from_str("3.7").unwrap(),
@@ -525,7 +526,7 @@ fn test_sum_iterator() {
 
 #[test]
 fn test_product_iterator() {
-    let values = vec![
+    let values = [
         CustomDecimal::from_str("2.0").unwrap(),
         CustomDecimal::from_str("3.0").unwrap(),
         CustomDecimal::from_str("4.0").unwrap(),
@@ -640,3 +641,88 @@ fn test_multi_precision_defi_scenario() {
 
     assert_eq!(eth_to_usdc_ratio, Decimal18::from_str("2500.0").unwrap());
 }
+
+// ========== Serde Precision Matrix Tests ==========
+
+fn representative_values<const D: u32>() -> Vec<Decimal<D>> {
+    vec![
+        Decimal::<D>::ZERO,
+        Decimal::<D>::ONE,
+        Decimal::<D>::MAX,
+        Decimal::<D>::raw(u128::MAX / 3),
+        Decimal::<D>::from_ratio(1u128, 3u128),
+        Decimal::<D>::raw(1),
+    ]
+}
+
+fn assert_same_precision_roundtrip<const D: u32>() {
+    for value in representative_values::<D>() {
+        let json = serde_json::to_string(&value).unwrap();
+        let back: Decimal<D> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, value, "Decimal<{}> roundtrip via {}", D, json);
+    }
+}
+
+fn assert_cross_precision<const D1: u32, const D2: u32>() {
+    for value in representative_values::<D1>() {
+        let json = serde_json::to_string(&value).unwrap();
+        let result: Result<Decimal<D2>, _> = serde_json::from_str(&json);
+        // strict-deserialize rejects anything truncation would change
+        let expected = value.try_to_precision::<D2>().filter(|narrowed| {
+            !cfg!(feature = "strict-deserialize") || narrowed.try_to_precision::<D1>() == Some(value)
+        });
+        match expected {
+            Some(expected) => assert_eq!(
+                result.unwrap(),
+                expected,
+                "Decimal<{}> -> Decimal<{}> via {}",
+                D1,
+                D2,
+                json
+            ),
+            None => assert!(
+                result.is_err(),
+                "Decimal<{}> -> Decimal<{}> via {} should fail",
+                D1,
+                D2,
+                json
+            ),
+        }
+    }
+}
+
+macro_rules! assert_cross_matrix {
+    ($($d1:literal),*) => {
+        $(assert_cross_matrix!(@row $d1; 0, 1, 2, 6, 8, 9, 12, 18);)*
+    };
+    (@row $d1:literal; $($d2:literal),*) => {
+        $(assert_cross_precision::<$d1, $d2>();)*
+    };
+}
+
+#[test]
+fn test_serde_roundtrip_precision_matrix() {
+    assert_same_precision_roundtrip::<0>();
+    assert_same_precision_roundtrip::<1>();
+    assert_same_precision_roundtrip::<2>();
+    assert_same_precision_roundtrip::<6>();
+    assert_same_precision_roundtrip::<8>();
+    assert_same_precision_roundtrip::<9>();
+    assert_same_precision_roundtrip::<12>();
+    assert_same_precision_roundtrip::<18>();
+}
+
+#[test]
+fn test_serde_cross_precision_matrix() {
+    assert_cross_matrix!(0, 1, 2, 6, 8, 9, 12, 18);
+}
+
+#[test]
+fn test_serde_integer_overflow_errors_instead_of_panicking() {
+    // Previously panicked with "attempt to multiply with overflow"
+    let json = serde_json::to_string(&Decimal::<0>::MAX).unwrap();
+    assert_eq!(json, format!(r#""{}""#, u128::MAX));
+    assert!(serde_json::from_str::<Decimal<1>>(&json).is_err());
+    assert!(serde_json::from_str::<Decimal6>(&json).is_err());
+    assert!(CustomDecimal::from_str(&u128::MAX.to_string()).is_err());
+}
@@ -0,0 +1,38 @@
+//! Smoke test for `no_std` + `alloc` builds.
+//!
+//! Run with `cargo test --no-default-features --test no_std_smoke`. The crate
+//! under test is then compiled without `std`; this test crate only links `std`
+//! for the test harness.
+
+#![no_std]
+
+extern crate alloc;
+extern crate std;
+
+use alloc::string::ToString;
+use core::str::FromStr;
+use cosmwasm_custom_decimal::{Decimal18, Decimal6, MoneyFormat, SignedDecimal};
+use cosmwasm_std::Uint128;
+
+#[test]
+fn test_display_and_from_str() {
+    let d = Decimal6::from_str("1_234.5").unwrap();
+    assert_eq!(d.to_string(), "1234.5");
+    assert_eq!(d.format_money(&MoneyFormat::default()), "1,234.50");
+
+    let signed = SignedDecimal::<6>::from_str("-2.25").unwrap();
+    assert_eq!(signed.to_string(), "-2.25");
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Decimal6::from_str("1.5").unwrap();
+    let b = Decimal6::from_str("2.5").unwrap();
+    assert_eq!(a + b, Decimal6::from(4u64));
+    assert_eq!(a * Uint128::new(10), Uint128::new(15));
+    assert_eq!(a.checked_div(Decimal6::ZERO), None);
+
+    let converted: Decimal18 = a.to_precision();
+    assert_eq!(converted.to_string(), "1.5");
+    assert_eq!(Decimal18::from(1000u64).log10(), Decimal18::from(3u64));
+}